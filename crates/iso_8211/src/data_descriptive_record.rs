@@ -11,6 +11,7 @@ pub struct DataDescriptiveRecord {
     // Ends with a field area 0x1E
     pub directory: Vec<DirectoryEntry>,
     // Field Area
+    pub control_field: ControlField,
 }
 
 impl DataDescriptiveRecord {
@@ -19,9 +20,12 @@ impl DataDescriptiveRecord {
         let directory = DirectoryEntry::parse_all(parser, &ledger)?;
 
         let control_field = ControlField::parse(parser, &ledger, &directory[0])?;
-        dbg!(control_field);
 
-        Ok(Self { ledger, directory })
+        Ok(Self {
+            ledger,
+            directory,
+            control_field,
+        })
     }
 }
 
@@ -45,8 +49,8 @@ impl DDRLedger {
         parser.expect_bytes(b"3LE1 09")?;
         let field_area_base_address = parser.read_string(5)?.parse::<u32>()?;
         parser.expect_bytes(b" ! ")?;
-        let field_length_size = parser.next()? - '0' as u8;
-        let field_position_size = parser.next()? - '0' as u8;
+        let field_length_size = parser.next()? - b'0';
+        let field_position_size = parser.next()? - b'0';
         parser.expect_bytes(b"04")?;
 
         Ok(Self {
@@ -113,16 +117,18 @@ impl Debug for DirectoryEntry {
     }
 }
 
+/// The first field of the record, naming the file and how its fields nest.
 #[derive(Debug)]
 pub struct ControlField {
-    file_title: String,
-    field_tag_pairs: Vec<(String, String)>,
+    pub file_title: String,
+    /// Pairs of a parent field tag and a child field tag.
+    pub field_tag_pairs: Vec<(String, String)>,
 }
 
 impl ControlField {
     pub fn parse<T: Read + Seek>(
         parser: &mut Parser<T>,
-        ledger: &DDRLedger,
+        _ledger: &DDRLedger,
         entry: &DirectoryEntry,
     ) -> Result<Self, Iso8211Error> {
         parser.expect_bytes(b"0000;&   ")?;
@@ -16,8 +16,8 @@ impl DRLedger {
         parser.expect_bytes(b" D     ")?;
         let field_area_base_address = parser.read_string(5)?.parse::<u32>()?;
         parser.expect_bytes(b"   ")?;
-        let field_length_size = parser.next()? - '0' as u8;
-        let field_position_size = parser.next()? - '0' as u8;
+        let field_length_size = parser.next()? - b'0';
+        let field_position_size = parser.next()? - b'0';
         parser.expect_bytes(b"04")?;
 
         Ok(Self {
@@ -1,3 +1,5 @@
+//! Reading of ISO/IEC 8211 files, the encoding of S-57 electronic navigational charts.
+//!
 //! ## References
 //! - [ISO/IEC 8211](https://iho.int/uploads/user/Services%20and%20Standards/S-100WG/MISC/US_S100-Part10a.pdf)
 //! - [iso8211 crate](https://crates.io/crates/iso8211) (unmaintained)
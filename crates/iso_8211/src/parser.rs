@@ -17,6 +17,8 @@ impl<T: Read + Seek> Parser<T> {
         Self { reader }
     }
 
+    // Unlike `Iterator::next`, reading past the end is an error
+    #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> Result<u8, Iso8211Error> {
         let mut buf = [0; 1];
         self.reader.read_exact(&mut buf)?;
@@ -0,0 +1,34 @@
+//! Throughput of the streaming decoder on a recorded log, repeated to several megabytes.
+//! Run with `cargo bench -p nmea_0183`.
+
+use nmea_0183::throughput::{self, Stage};
+
+/// A second of a 10 Hz multi-GNSS receiver sending UBX frames alongside its sentences,
+/// with instruments behind a multiplexer and AIS.
+const LOG: &[u8] = include_bytes!("data/multi_gnss.log");
+
+/// Size the log is repeated to.
+const SIZE: usize = 8 << 20;
+
+/// Number of times to decode the log for each stage.
+const ITERATIONS: u32 = 10;
+
+fn main() {
+    let data = LOG.repeat(SIZE.div_ceil(LOG.len()));
+    println!("{:.1} MB, {ITERATIONS} iterations", data.len() as f64 / 1e6);
+
+    for stage in Stage::ALL {
+        let throughput =
+            throughput::measure(&data, stage, ITERATIONS).expect("Failed to read frame");
+        assert_eq!(
+            throughput.counts.errors, 0,
+            "The log should decode without errors"
+        );
+
+        println!(
+            "{stage:?}: {:.1} MB/s, {:.0} frames/s",
+            throughput.megabytes_per_second(),
+            throughput.frames_per_second()
+        );
+    }
+}
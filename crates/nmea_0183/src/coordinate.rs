@@ -0,0 +1,110 @@
+use core::{fmt::Debug, str};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+use super::{
+    parser::{FromParser, Parser},
+    Nmea0183Error,
+};
+
+/// `ddmm.mm,d`
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Coordinate {
+    pub degree: f32,
+}
+
+impl Coordinate {
+    pub fn new() -> Self {
+        Self { degree: 0.0 }
+    }
+}
+
+impl<'a> FromParser<'a> for Coordinate {
+    const NAME: &'static str = "coordinate";
+    const FIELDS: usize = 2;
+
+    // Empty coordinates are an error, parse them with `parse_optional` if they can be missing
+    fn parse(parser: &mut Parser<'a>) -> Result<Self, Nmea0183Error> {
+        if matches!(parser.peek(), Some(',') | None) {
+            return Err(Nmea0183Error::EmptyField);
+        }
+
+        // The last two digits before the decimal point start the minutes,
+        // latitudes have two degree digits and longitudes three
+        let value = str::from_utf8(parser.take_until(',')?)?;
+        let direction = parser.next()?;
+
+        if !matches!(direction, 'N' | 'S' | 'E' | 'W') {
+            return Err(Nmea0183Error::UnexpectedChar(direction));
+        }
+
+        // Only digits are sliced below, other characters could split a multi-byte character
+        if let Some(c) = value.chars().find(|c| !matches!(c, '.' | '0'..='9')) {
+            return Err(Nmea0183Error::UnexpectedChar(c));
+        }
+
+        let split = value.find('.').unwrap_or(value.len());
+        if split < 2 {
+            return Err(Nmea0183Error::IncorrectLength);
+        }
+
+        let degrees = match &value[..split - 2] {
+            "" => 0.0,
+            degrees => degrees.parse::<f32>()?,
+        };
+        let minutes = value[split - 2..].parse::<f32>()?;
+        let negative = direction == 'S' || direction == 'W';
+
+        let degrees = (degrees + minutes / 60.0) * if negative { -1.0 } else { 1.0 };
+        Ok(Self { degree: degrees })
+    }
+}
+
+impl Debug for Coordinate {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let deg = self.degree.abs();
+        let degrees = deg.floor();
+        let minutes = (deg - degrees) * 60.0;
+        let seconds = minutes.fract() * 60.0;
+        let direction = if self.degree < 0.0 { "S" } else { "N" };
+        f.write_fmt(format_args!(
+            "{}°{}'{}\"{}",
+            degrees,
+            minutes.floor(),
+            seconds.floor(),
+            direction
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(data: &str) -> Result<Coordinate, Nmea0183Error> {
+        Parser::new(data.as_bytes()).parse::<Coordinate>()
+    }
+
+    #[test]
+    fn parses_degrees_and_minutes() {
+        let latitude = parse("4807.038,N").unwrap();
+        assert!((latitude.degree - 48.1173).abs() < 1e-4);
+
+        let longitude = parse("01131.000,W").unwrap();
+        assert!((longitude.degree + 11.516_666).abs() < 1e-4);
+    }
+
+    #[test]
+    fn rejects_values_without_minutes() {
+        assert!(parse("1.5,N").is_err());
+        assert!(parse(".5,N").is_err());
+    }
+
+    #[test]
+    fn rejects_non_ascii_values() {
+        assert!(parse("\u{e9}a,N").is_err());
+        assert!(parse("48\u{e9}7.0,N").is_err());
+    }
+}
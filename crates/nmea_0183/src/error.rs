@@ -0,0 +1,58 @@
+use core::num;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use thiserror::Error;
+
+#[cfg(feature = "alloc")]
+use crate::String;
+
+#[derive(Debug, Clone, Error)]
+pub enum Nmea0183Error {
+    #[error("The message is missing the `$` prefix")]
+    MissingPrefix,
+    #[error("The length of the input was not long enough")]
+    IncorrectLength,
+    #[error("The message's checksum did not match")]
+    InvalidChecksum,
+    #[error("An integer was invalid")]
+    InvalidNumber(#[from] num::ParseIntError),
+    #[error("A float was invalid")]
+    InvalidFloat(#[from] num::ParseFloatError),
+    #[error("Unknown message type")]
+    UnknownType([u8; 3]),
+    #[error("Non UTF-8 character")]
+    NonUtf8(#[from] core::str::Utf8Error),
+    #[error("Unexpected character {0:?}")]
+    UnexpectedChar(char),
+    #[error("Parser has remaining data")]
+    RemainingData,
+    #[error("Incomplete data")]
+    Incomplete,
+    #[error("The field is empty")]
+    EmptyField,
+    #[error("A list or text is longer than its fixed capacity")]
+    Capacity,
+    /// Only with the `alloc` feature, otherwise errors are returned without where they are.
+    #[cfg(feature = "alloc")]
+    #[error("{0}")]
+    InvalidField(Box<FieldError>),
+}
+
+/// An invalid field, with where it is in the line.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Error)]
+#[error("Field {field} at byte {offset}: expected {expected}, got {raw:?} ({error})")]
+pub struct FieldError {
+    /// Index of the field, the first field after the address is 1.
+    pub field: usize,
+    /// Byte offset of the field from the start of the line.
+    pub offset: usize,
+    /// Length of the field in bytes, without the separator.
+    pub length: usize,
+    /// Name of the expected value.
+    pub expected: &'static str,
+    /// The text of the field.
+    pub raw: String,
+    pub error: Box<Nmea0183Error>,
+}
@@ -4,6 +4,7 @@ use super::{
 };
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FaaMode {
     Autonomous,
     Caution,
@@ -27,7 +28,7 @@ impl FaaMode {
             'E' => Self::Estimated,
             'F' => Self::RtkFloat,
             'M' => Self::ManualInput,
-            'N' => Self::DataNotValid,
+            'N' | 'V' => Self::DataNotValid,
             'P' => Self::Precise,
             'R' => Self::RtkInteger,
             'S' => Self::Simulated,
@@ -35,9 +36,27 @@ impl FaaMode {
             _ => return None,
         })
     }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Self::Autonomous => 'A',
+            Self::Caution => 'C',
+            Self::Differential => 'D',
+            Self::Estimated => 'E',
+            Self::RtkFloat => 'F',
+            Self::ManualInput => 'M',
+            Self::DataNotValid => 'N',
+            Self::Precise => 'P',
+            Self::RtkInteger => 'R',
+            Self::Simulated => 'S',
+            Self::Unsafe => 'U',
+        }
+    }
 }
 
 impl<'a> FromParser<'a> for FaaMode {
+    const NAME: &'static str = "FAA mode";
+
     fn parse(parser: &mut Parser<'a>) -> Result<Self, Nmea0183Error> {
         let chr = parser.next()?;
         Self::from_char(chr).ok_or(Nmea0183Error::UnexpectedChar(chr))
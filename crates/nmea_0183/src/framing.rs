@@ -0,0 +1,245 @@
+//! Splits a byte stream carrying both NMEA 0183 sentences and UBX frames.
+
+use std::{
+    io::{self, Read},
+    ops::Range,
+};
+
+use crate::ubx;
+
+/// Lines longer than this are discarded, as they are most likely noise.
+pub const MAX_LINE_LENGTH: usize = 1024;
+/// Size of the receive buffer, enough for the largest UBX frame followed by a line.
+pub const BUFFER_SIZE: usize = ubx::MAX_PAYLOAD_LENGTH + ubx::FRAME_OVERHEAD + MAX_LINE_LENGTH;
+
+/// A frame borrowed from the receive buffer, valid until more data is received.
+#[derive(Debug, Clone, Copy)]
+pub enum Frame<'a> {
+    /// A line starting with `$`, `!` or a TAG block, without the line ending.
+    Nmea(&'a [u8]),
+    /// A complete UBX frame with a valid checksum, from the sync characters to the checksum.
+    Ubx(&'a [u8]),
+}
+
+/// Buffers received bytes and splits them into frames.
+/// Bytes outside of a frame, incomplete lines and UBX frames with an invalid checksum are skipped.
+///
+/// The buffer has a fixed size, so nothing is allocated after creation.
+/// Frames are borrowed from it, the unread bytes are moved back to the front once the end is reached.
+struct Framer {
+    buffer: Box<[u8]>,
+    /// Start of the bytes that have not been split yet.
+    start: usize,
+    /// End of the received bytes.
+    end: usize,
+}
+
+impl Framer {
+    fn new() -> Self {
+        Self {
+            buffer: vec![0; BUFFER_SIZE].into_boxed_slice(),
+            start: 0,
+            end: 0,
+        }
+    }
+
+    /// Free space at the end of the buffer to receive into, followed by [`Framer::filled`].
+    fn space(&mut self) -> &mut [u8] {
+        if self.start == self.end {
+            self.start = 0;
+            self.end = 0;
+        } else if self.end == self.buffer.len() {
+            self.buffer.copy_within(self.start..self.end, 0);
+            self.end -= self.start;
+            self.start = 0;
+        }
+
+        &mut self.buffer[self.end..]
+    }
+
+    /// Marks bytes written to [`Framer::space`] as received.
+    fn filled(&mut self, len: usize) {
+        self.end = (self.end + len).min(self.buffer.len());
+    }
+
+    fn frame(&self, kind: FrameKind, range: Range<usize>) -> Frame<'_> {
+        match kind {
+            FrameKind::Nmea => Frame::Nmea(&self.buffer[range]),
+            FrameKind::Ubx => Frame::Ubx(&self.buffer[range]),
+        }
+    }
+
+    /// Finds the next complete frame, returning where it is in the buffer, or `None` if more data is needed.
+    fn next_range(&mut self) -> Option<(FrameKind, Range<usize>)> {
+        loop {
+            let Some(start) = self
+                .data()
+                .iter()
+                .position(|&x| matches!(x, b'$' | b'!' | b'\\') || x == ubx::SYNC[0])
+            else {
+                self.start = self.end;
+                return None;
+            };
+            self.start += start;
+
+            let frame = if self.data()[0] == ubx::SYNC[0] {
+                self.ubx_frame()
+            } else {
+                self.nmea_frame()
+            };
+
+            match frame {
+                Ok(found) => {
+                    let range = self.start..self.start + found.length;
+                    self.start += found.consumed;
+                    return Some((found.kind, range));
+                }
+                Err(FrameError::Incomplete) => return None,
+                // Skip the start of the frame and look for the next one
+                Err(FrameError::Invalid(end)) => {
+                    self.start += end;
+                }
+            }
+        }
+    }
+
+    /// The bytes that have not been split yet.
+    fn data(&self) -> &[u8] {
+        &self.buffer[self.start..self.end]
+    }
+
+    fn ubx_frame(&self) -> Result<Found, FrameError> {
+        let data = self.data();
+        if data.len() < ubx::HEADER_LENGTH {
+            // Check the second sync character early, so a single 0xB5 doesn't delay lines
+            return match data.get(1) {
+                Some(&x) if x != ubx::SYNC[1] => Err(FrameError::Invalid(1)),
+                _ => Err(FrameError::Incomplete),
+            };
+        }
+        if data[1] != ubx::SYNC[1] {
+            return Err(FrameError::Invalid(1));
+        }
+
+        let length = ubx::payload_length(data);
+        if length > ubx::MAX_PAYLOAD_LENGTH {
+            return Err(FrameError::Invalid(1));
+        }
+        let length = length + ubx::FRAME_OVERHEAD;
+        if data.len() < length {
+            return Err(FrameError::Incomplete);
+        }
+        if !ubx::valid_checksum(&data[..length]) {
+            return Err(FrameError::Invalid(1));
+        }
+
+        Ok(Found {
+            kind: FrameKind::Ubx,
+            length,
+            consumed: length,
+        })
+    }
+
+    fn nmea_frame(&self) -> Result<Found, FrameError> {
+        let data = self.data();
+
+        // A TAG block is followed by the sentence, so its closing `\` doesn't end the line
+        let mut start = 1;
+        if data[0] == b'\\' {
+            match find_end(data, 1) {
+                Some(end) if data[end] == b'\\' => match data.get(end + 1) {
+                    Some(b'$' | b'!') => start = end + 2,
+                    Some(_) => return Err(FrameError::Invalid(end + 1)),
+                    None => return incomplete(data),
+                },
+                Some(end) => return Err(FrameError::Invalid(end)),
+                None => return incomplete(data),
+            }
+        }
+
+        match find_end(data, start) {
+            Some(end) if data[end] == b'\n' => {
+                let mut length = end;
+                while length > 0 && data[length - 1] == b'\r' {
+                    length -= 1;
+                }
+
+                Ok(Found {
+                    kind: FrameKind::Nmea,
+                    length,
+                    consumed: end + 1,
+                })
+            }
+            Some(end) => Err(FrameError::Invalid(end)),
+            None => incomplete(data),
+        }
+    }
+}
+
+/// Finds the end of a line, or the start of another frame, from `start`.
+/// Sentences are ASCII and `$`, `!` and `\` are reserved, so any of them starts a new frame.
+fn find_end(data: &[u8], start: usize) -> Option<usize> {
+    data.get(start..)?
+        .iter()
+        .position(|&x| matches!(x, b'\n' | b'$' | b'!' | b'\\') || !x.is_ascii())
+        .map(|x| x + start)
+}
+
+fn incomplete(data: &[u8]) -> Result<Found, FrameError> {
+    match data.len() > MAX_LINE_LENGTH {
+        true => Err(FrameError::Invalid(1)),
+        false => Err(FrameError::Incomplete),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum FrameKind {
+    Nmea,
+    Ubx,
+}
+
+/// A complete frame at the start of the unread bytes.
+struct Found {
+    kind: FrameKind,
+    /// Length of the frame, without the line ending.
+    length: usize,
+    /// Number of bytes to skip to get past the frame.
+    consumed: usize,
+}
+
+enum FrameError {
+    /// More data is needed.
+    Incomplete,
+    /// Not a valid frame, the bytes before this index should be skipped.
+    Invalid(usize),
+}
+
+/// Reads frames from a reader, such as a serial port.
+pub struct FrameReader<R> {
+    reader: R,
+    framer: Framer,
+}
+
+impl<R: Read> FrameReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            framer: Framer::new(),
+        }
+    }
+
+    /// Reads until a complete frame is received.
+    pub fn next_frame(&mut self) -> io::Result<Frame<'_>> {
+        loop {
+            if let Some((kind, range)) = self.framer.next_range() {
+                return Ok(self.framer.frame(kind, range));
+            }
+
+            let read = self.reader.read(self.framer.space())?;
+            if read == 0 {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+            self.framer.filled(read);
+        }
+    }
+}
@@ -0,0 +1,409 @@
+//! Decoding of NMEA 0183 sentences and the UBX frames u-blox receivers send alongside them,
+//! and stores that keep the latest value of everything received.
+//!
+//! This crate has no GUI or serial port dependencies, so it can be used by any tool reading NMEA 0183 data.
+//!
+//! ## Features
+//! - `std` (default): the stores, stream framing and logging. Implies `alloc`.
+//! - `alloc`: heap allocated text, encoding sentences, UBX packets and the diagnostics of lenient parsing.
+//!   Without it the crate is `no_std` and doesn't allocate, text is limited to [`MAX_SENTENCE_LENGTH`].
+//! - `serde`: serializing packets and snapshots of the stores.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// Types are created with `new` rather than `Default`
+#![allow(clippy::new_without_default)]
+// Without a heap, lists and text are stored inline
+#![cfg_attr(not(feature = "alloc"), allow(clippy::large_enum_variant))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::str;
+
+use chrono::{DateTime, Utc};
+
+use packets::geographic_position::GeographicPosition;
+
+#[cfg(feature = "alloc")]
+use alloc::{format, vec::Vec};
+
+#[cfg(feature = "alloc")]
+use self::{error::FieldError, writer::Writer};
+use self::{
+    error::Nmea0183Error,
+    packets::{
+        active_satellites::ActiveSatellites, autopilot::Autopilot,
+        bearing_distance_waypoint::BearingDistanceWaypoint,
+        bearing_origin_destination::BearingOriginDestination, cross_track_error::CrossTrackError,
+        depth_below::DepthBelow, depth_of_water::DepthOfWater, distance_log::DistanceLog,
+        gnss_fix_data::GnssFixData, ground_speed::GroundSpeed,
+        heading_deviation_variation::HeadingDeviationVariation, magnetic_heading::MagneticHeading,
+        pseudorange_error_statistics::PseudorangeErrorStatistics, rate_of_turn::RateOfTurn,
+        recommended_minimum::RecommendedMinimum, recommended_navigation::RecommendedNavigation,
+        route::Route, satellite_fault_detection::SatelliteFaultDetection,
+        satellites_in_view::SatellitesInView, text::Text, time_date::TimeDate,
+        transducer_measurement::TransducerMeasurements, true_heading::TrueHeading,
+        true_heading_status::TrueHeadingStatus, water_speed_heading::WaterSpeedHeading,
+        water_temperature::WaterTemperature, waypoint_location::WaypointLocation,
+        wind_angle::WindAngle, wind_direction_speed::WindDirectionSpeed,
+        wind_speed_angle::WindSpeedAngle,
+    },
+    parser::Parser,
+    proprietary::{Decoders, Proprietary},
+    tag_block::TagBlock,
+    talker::Talker,
+    view::{Address, SentenceView},
+};
+
+pub mod coordinate;
+pub mod error;
+pub mod faa_mode;
+#[cfg(feature = "std")]
+pub mod framing;
+#[cfg(feature = "std")]
+pub mod log;
+#[cfg(feature = "std")]
+pub mod misc;
+pub mod navigation;
+pub mod packets;
+pub mod parser;
+pub mod proprietary;
+#[cfg(feature = "std")]
+pub mod stores;
+pub mod tag_block;
+pub mod talker;
+#[cfg(feature = "std")]
+pub mod throughput;
+pub mod time;
+#[cfg(feature = "alloc")]
+pub mod ubx;
+pub mod variation;
+pub mod view;
+#[cfg(feature = "alloc")]
+pub mod writer;
+
+/// Longest sentence allowed by the standard, from the `$` to the line ending.
+pub const MAX_SENTENCE_LENGTH: usize = 82;
+
+/// Text in packets, fixed capacity without the `alloc` feature.
+#[cfg(feature = "alloc")]
+pub type String = alloc::string::String;
+/// Text in packets, fixed capacity without the `alloc` feature.
+#[cfg(not(feature = "alloc"))]
+pub type String = heapless::String<MAX_SENTENCE_LENGTH>;
+
+/// A list of values in a packet, with a capacity of `N` without the `alloc` feature.
+#[cfg(feature = "alloc")]
+pub type List<T, const N: usize> = Vec<T>;
+/// A list of values in a packet, with a capacity of `N` without the `alloc` feature.
+#[cfg(not(feature = "alloc"))]
+pub type List<T, const N: usize> = heapless::Vec<T, N>;
+
+/// Copies text into a [`String`], failing if it doesn't fit.
+fn text(value: &str) -> Result<String, Nmea0183Error> {
+    #[cfg(feature = "alloc")]
+    let value = String::from(value);
+    #[cfg(not(feature = "alloc"))]
+    let value = String::try_from(value).map_err(|_| Nmea0183Error::Capacity)?;
+    Ok(value)
+}
+
+/// Adding to a [`List`], which fails if it has a fixed capacity and is full.
+trait TryPush<T> {
+    fn try_push(&mut self, value: T) -> Result<(), Nmea0183Error>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> TryPush<T> for Vec<T> {
+    fn try_push(&mut self, value: T) -> Result<(), Nmea0183Error> {
+        self.push(value);
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+impl<T, const N: usize> TryPush<T> for heapless::Vec<T, N> {
+    fn try_push(&mut self, value: T) -> Result<(), Nmea0183Error> {
+        self.push(value).map_err(|_| Nmea0183Error::Capacity)
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Message {
+    /// The source of the message.
+    pub talker: Talker,
+    /// The TAG block before the sentence, if any.
+    pub tag_block: Option<TagBlock>,
+    /// The type of message.
+    pub message: Sentence,
+}
+
+//RMC, GSA, GSV, GLL, VTG, HDT, HDM, HDG, THS, ROT, DBT, DBS, DPT, MWV, MWD, VWR, VWT, VHW, VLW, MTW, XDR, ZDA, GST, GBS, GNS, RMB, APB, BOD, BWC, XTE, WPL, RTE
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Sentence {
+    /// Recommended Minimum Navigation Information.
+    Rmc(RecommendedMinimum),
+    Gsa(ActiveSatellites),
+    /// Satellites in view
+    Gsv(SatellitesInView),
+    /// Geographic Position
+    Gll(GeographicPosition),
+    /// Track Made Good and Ground Speed.
+    Vtg(GroundSpeed),
+    /// Text for display.
+    Txt(Text),
+    /// Heading, true.
+    Hdt(TrueHeading),
+    /// Heading, magnetic.
+    Hdm(MagneticHeading),
+    /// Heading, deviation and variation.
+    Hdg(HeadingDeviationVariation),
+    /// True heading and status.
+    Ths(TrueHeadingStatus),
+    /// Rate of turn.
+    Rot(RateOfTurn),
+    /// Depth below transducer.
+    Dbt(DepthBelow),
+    /// Depth below surface.
+    Dbs(DepthBelow),
+    /// Depth of water.
+    Dpt(DepthOfWater),
+    /// Wind speed and angle.
+    Mwv(WindSpeedAngle),
+    /// Wind direction and speed.
+    Mwd(WindDirectionSpeed),
+    /// Relative wind speed and angle.
+    Vwr(WindAngle),
+    /// True wind speed and angle.
+    Vwt(WindAngle),
+    /// Water speed and heading.
+    Vhw(WaterSpeedHeading),
+    /// Distance traveled through water.
+    Vlw(DistanceLog),
+    /// Mean temperature of water.
+    Mtw(WaterTemperature),
+    /// Transducer measurement.
+    Xdr(TransducerMeasurements),
+    /// Time and date.
+    Zda(TimeDate),
+    /// Pseudorange error statistics.
+    Gst(PseudorangeErrorStatistics),
+    /// Satellite fault detection.
+    Gbs(SatelliteFaultDetection),
+    /// GNSS fix data.
+    Gns(GnssFixData),
+    /// Recommended minimum navigation information, to a waypoint.
+    Rmb(RecommendedNavigation),
+    /// Heading/track controller (autopilot) sentence B.
+    Apb(Autopilot),
+    /// Bearing from origin to destination.
+    Bod(BearingOriginDestination),
+    /// Bearing and distance to waypoint.
+    Bwc(BearingDistanceWaypoint),
+    /// Cross track error.
+    Xte(CrossTrackError),
+    /// Waypoint location.
+    Wpl(WaypointLocation),
+    /// Routes.
+    Rte(Route),
+    /// Proprietary sentence, defined by a manufacturer.
+    Proprietary(Proprietary),
+}
+
+fn checksum(sentence: &[u8]) -> u8 {
+    let mut out = 0;
+    for byte in sentence {
+        if matches!(byte, b'$' | b'!' | b'*') {
+            continue;
+        }
+
+        out ^= byte;
+    }
+    out
+}
+
+impl Message {
+    /// Parses a message, failing on the first invalid field.
+    pub fn parse(bytes: &[u8]) -> Result<Message, Nmea0183Error> {
+        SentenceView::parse(bytes)?.message()
+    }
+
+    /// Parses a message, decoding proprietary sentences with the given decoders.
+    pub fn parse_with(bytes: &[u8], decoders: &Decoders) -> Result<Message, Nmea0183Error> {
+        SentenceView::parse(bytes)?.message_with(decoders)
+    }
+
+    /// Parses a message, skipping invalid optional fields instead of failing.
+    /// Returns the message, which only fails if a required field is invalid, and every invalid field.
+    #[cfg(feature = "alloc")]
+    pub fn parse_lenient(bytes: &[u8]) -> (Result<Message, Nmea0183Error>, Vec<FieldError>) {
+        let view = match SentenceView::parse(bytes) {
+            Ok(view) => view,
+            Err(err) => return (Err(err), Vec::new()),
+        };
+
+        let mut parser = view.parser().lenient(true);
+        let message = Self::decode(&view, &mut parser, &Decoders::BUILTIN);
+        (message, parser.take_diagnostics())
+    }
+
+    /// Decodes the sentence of a view with a parser of its fields.
+    fn decode(
+        view: &SentenceView,
+        parser: &mut Parser,
+        decoders: &Decoders,
+    ) -> Result<Message, Nmea0183Error> {
+        let tag_block = view.tag_block.map(TagBlock::parse).transpose()?;
+
+        let (talker, message) = match view.address {
+            Address::Standard {
+                talker,
+                sentence_type,
+            } => (Talker::Id(talker), parse_sentence(sentence_type, parser)),
+            Address::Proprietary { manufacturer } => (
+                Talker::Proprietary,
+                Proprietary::parse_with(manufacturer, parser, decoders).map(Sentence::Proprietary),
+            ),
+        };
+        let message = match message {
+            Err(err @ Nmea0183Error::UnknownType(_)) => Err(err),
+            Err(err) => Err(parser.locate(err)),
+            Ok(message) => Ok(message),
+        };
+
+        Ok(Self {
+            talker,
+            tag_block,
+            message: message?,
+        })
+    }
+
+    /// Time the message was received, from the TAG block.
+    pub fn time(&self) -> Option<DateTime<Utc>> {
+        self.tag_block.as_ref().and_then(|x| x.time)
+    }
+
+    /// Source of the message, from the TAG block.
+    pub fn source(&self) -> Option<&str> {
+        self.tag_block.as_ref().and_then(|x| x.source.as_deref())
+    }
+
+    /// Encodes the message as a sentence, including the line ending.
+    /// Returns `None` for sentence types that can not be encoded.
+    #[cfg(feature = "alloc")]
+    pub fn encode(&self) -> Option<String> {
+        let mut writer = Writer::new();
+        let sentence_type = match &self.message {
+            Sentence::Rmb(sentence) => {
+                sentence.write(&mut writer);
+                "RMB"
+            }
+            Sentence::Apb(sentence) => {
+                sentence.write(&mut writer);
+                "APB"
+            }
+            Sentence::Bod(sentence) => {
+                sentence.write(&mut writer);
+                "BOD"
+            }
+            Sentence::Bwc(sentence) => {
+                sentence.write(&mut writer);
+                "BWC"
+            }
+            Sentence::Xte(sentence) => {
+                sentence.write(&mut writer);
+                "XTE"
+            }
+            Sentence::Wpl(sentence) => {
+                sentence.write(&mut writer);
+                "WPL"
+            }
+            Sentence::Rte(sentence) => {
+                sentence.write(&mut writer);
+                "RTE"
+            }
+            _ => return None,
+        };
+
+        let Talker::Id(talker) = &self.talker else {
+            return None;
+        };
+        let talker = str::from_utf8(talker).ok()?;
+        Some(writer.finish(&format!("{talker}{sentence_type}")))
+    }
+}
+
+fn parse_sentence(packet_type: [u8; 3], parser: &mut Parser) -> Result<Sentence, Nmea0183Error> {
+    Ok(match &packet_type {
+        b"RMC" => Sentence::Rmc(RecommendedMinimum::parse(parser)?),
+        b"GLL" => Sentence::Gll(GeographicPosition::parse(parser)?),
+        b"GSV" => Sentence::Gsv(SatellitesInView::parse(parser)?),
+        b"GSA" => Sentence::Gsa(ActiveSatellites::parse(parser)?),
+        b"VTG" => Sentence::Vtg(GroundSpeed::parse(parser)?),
+        b"TXT" => Sentence::Txt(Text::parse(parser)?),
+        b"HDT" => Sentence::Hdt(TrueHeading::parse(parser)?),
+        b"HDM" => Sentence::Hdm(MagneticHeading::parse(parser)?),
+        b"HDG" => Sentence::Hdg(HeadingDeviationVariation::parse(parser)?),
+        b"THS" => Sentence::Ths(TrueHeadingStatus::parse(parser)?),
+        b"ROT" => Sentence::Rot(RateOfTurn::parse(parser)?),
+        b"DBT" => Sentence::Dbt(DepthBelow::parse(parser)?),
+        b"DBS" => Sentence::Dbs(DepthBelow::parse(parser)?),
+        b"DPT" => Sentence::Dpt(DepthOfWater::parse(parser)?),
+        b"MWV" => Sentence::Mwv(WindSpeedAngle::parse(parser)?),
+        b"MWD" => Sentence::Mwd(WindDirectionSpeed::parse(parser)?),
+        b"VWR" => Sentence::Vwr(WindAngle::parse(parser)?),
+        b"VWT" => Sentence::Vwt(WindAngle::parse(parser)?),
+        b"VHW" => Sentence::Vhw(WaterSpeedHeading::parse(parser)?),
+        b"VLW" => Sentence::Vlw(DistanceLog::parse(parser)?),
+        b"MTW" => Sentence::Mtw(WaterTemperature::parse(parser)?),
+        b"XDR" => Sentence::Xdr(TransducerMeasurements::parse(parser)?),
+        b"ZDA" => Sentence::Zda(TimeDate::parse(parser)?),
+        b"GST" => Sentence::Gst(PseudorangeErrorStatistics::parse(parser)?),
+        b"GBS" => Sentence::Gbs(SatelliteFaultDetection::parse(parser)?),
+        b"GNS" => Sentence::Gns(GnssFixData::parse(parser)?),
+        b"RMB" => Sentence::Rmb(RecommendedNavigation::parse(parser)?),
+        b"APB" => Sentence::Apb(Autopilot::parse(parser)?),
+        b"BOD" => Sentence::Bod(BearingOriginDestination::parse(parser)?),
+        b"BWC" => Sentence::Bwc(BearingDistanceWaypoint::parse(parser)?),
+        b"XTE" => Sentence::Xte(CrossTrackError::parse(parser)?),
+        b"WPL" => Sentence::Wpl(WaypointLocation::parse(parser)?),
+        b"RTE" => Sentence::Rte(Route::parse(parser)?),
+        _ => return Err(Nmea0183Error::UnknownType(packet_type)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksum_skips_only_delimiters() {
+        let xor = |data: &[u8]| data.iter().fold(0, |acc, x| acc ^ x);
+        assert_eq!(checksum(b"$IIHDT,274.1,T"), xor(b"IIHDT,274.1,T"));
+        assert_eq!(
+            checksum(b"!AIVDM,1,1,,A,13u?,0"),
+            xor(b"AIVDM,1,1,,A,13u?,0")
+        );
+    }
+
+    #[test]
+    fn parses_integrated_instrument_talkers() {
+        let msg = Message::parse(b"$IIHDT,274.1,T*22").unwrap();
+        assert!(matches!(msg.talker, Talker::Id(id) if id == *b"II"));
+        let Sentence::Hdt(sentence) = msg.message else {
+            panic!("Expected HDT, got {:?}", msg.message);
+        };
+        assert_eq!(sentence.heading, Some(274.1));
+    }
+
+    #[test]
+    fn parses_mode_v_as_not_valid() {
+        let msg = Message::parse(b"$GPTHS,77.5,V*15").unwrap();
+        let Sentence::Ths(sentence) = msg.message else {
+            panic!("Expected THS, got {:?}", msg.message);
+        };
+        assert!(matches!(sentence.mode, faa_mode::FaaMode::DataNotValid));
+    }
+}
@@ -13,6 +13,16 @@ pub enum DelayedResult<T> {
     Outdated,
 }
 
+impl<T> DelayedResult<T> {
+    /// Gets the data if it is defined and not outdated.
+    pub fn ok(self) -> Option<T> {
+        match self {
+            DelayedResult::Ok(data) => Some(data),
+            _ => None,
+        }
+    }
+}
+
 impl<T> Delayed<T> {
     pub fn new() -> Self {
         Self {
@@ -60,3 +70,11 @@ impl<T> Delayed<T> {
         }
     }
 }
+
+/// Serializes the data if it is defined and not outdated, as a snapshot of the current value.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Delayed<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().ok().serialize(serializer)
+    }
+}
@@ -0,0 +1,79 @@
+use core::str;
+
+use super::{
+    parser::{FromParser, Parser},
+    Nmea0183Error,
+};
+use crate::quick_parser;
+
+/// Direction to steer to get back on track.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SteerDirection {
+    Left,
+    Right,
+}
+
+quick_parser!(SteerDirection, {
+    'L' => Left,
+    'R' => Right,
+});
+
+/// Whether the arrival circle of a waypoint, or the perpendicular through it, was entered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ArrivalStatus {
+    Arrived,
+    NotArrived,
+}
+
+quick_parser!(ArrivalStatus, {
+    'A' => Arrived,
+    'V' => NotArrived,
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BearingReference {
+    True,
+    Magnetic,
+}
+
+quick_parser!(BearingReference, {
+    'T' => True,
+    'M' => Magnetic,
+});
+
+/// `x.x,a`
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bearing {
+    pub degree: f32,
+    pub reference: BearingReference,
+}
+
+impl<'a> FromParser<'a> for Option<Bearing> {
+    const NAME: &'static str = "bearing";
+    const FIELDS: usize = 2;
+
+    fn parse(parser: &mut Parser<'a>) -> Result<Self, Nmea0183Error> {
+        let value = parser.take_until(',')?;
+        let reference = parser.take_until_or_end(',');
+
+        if value.is_empty() {
+            return Ok(None);
+        }
+
+        let reference = match reference {
+            b"T" => BearingReference::True,
+            b"M" => BearingReference::Magnetic,
+            [chr, ..] => return Err(Nmea0183Error::UnexpectedChar(*chr as char)),
+            [] => return Err(Nmea0183Error::Incomplete),
+        };
+
+        Ok(Some(Bearing {
+            degree: str::from_utf8(value)?.parse::<f32>()?,
+            reference,
+        }))
+    }
+}
@@ -1,16 +1,19 @@
-use crate::{
-    nmea_0183::{error::Nmea0183Error, parser::Parser},
-    quick_parser,
-};
+use heapless::Vec;
+
+use crate::{error::Nmea0183Error, parser::Parser, quick_parser};
+
+/// Number of satellite ID fields in a sentence.
+pub const MAX_SATELLITES: usize = 12;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ActiveSatellites {
     /// Current selection mode
     pub selection: SelectionMode,
     /// Current fix mode
     pub fix: Fix,
     /// IDs of satellites in view
-    pub satellites: Box<[SatelliteId]>,
+    pub satellites: Vec<SatelliteId, MAX_SATELLITES>,
     /// Position (3D) dilution of precision
     pub pdop: f32,
     /// Horizontal dilution of precision
@@ -20,9 +23,11 @@ pub struct ActiveSatellites {
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct SatelliteId(u8);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SatelliteId(pub u8);
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SelectionMode {
     /// Forced to operate in 2D or 3D mode
     Manual,
@@ -31,6 +36,7 @@ pub enum SelectionMode {
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Fix {
     /// No fix available
     NoFix,
@@ -41,15 +47,15 @@ pub enum Fix {
 }
 
 impl ActiveSatellites {
-    pub fn parse(sentence: &[u8]) -> Result<ActiveSatellites, Nmea0183Error> {
-        let mut parser = Parser::new(sentence).take_on_parse(',');
+    pub fn parse(parser: &mut Parser) -> Result<ActiveSatellites, Nmea0183Error> {
         let selection = parser.parse::<SelectionMode>()?;
         let mode = parser.parse::<Fix>()?;
 
         let mut satellites = Vec::new();
-        for _ in 0..12 {
+        for _ in 0..MAX_SATELLITES {
             if parser.peek().is_some_and(|x| x.is_ascii_digit()) {
-                satellites.push(SatelliteId(parser.parse::<u8>()?));
+                // Can't fail, as there is at most one satellite per field
+                let _ = satellites.push(SatelliteId(parser.parse::<u8>()?));
                 continue;
             }
             parser.expect(',')?;
@@ -59,7 +65,6 @@ impl ActiveSatellites {
         let hdop = parser.parse::<f32>()?;
         let vdop = parser.parse::<f32>()?;
 
-        let satellites = satellites.into_boxed_slice();
         Ok(ActiveSatellites {
             selection,
             fix: mode,
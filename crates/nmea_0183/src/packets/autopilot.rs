@@ -0,0 +1,82 @@
+#[cfg(feature = "alloc")]
+use crate::writer::Writer;
+use crate::{
+    error::Nmea0183Error,
+    faa_mode::FaaMode,
+    navigation::{ArrivalStatus, Bearing, SteerDirection},
+    packets::geographic_position::Status,
+    parser::Parser,
+    String,
+};
+
+/// `A,A,x.x,a,N,A,A,x.x,a,c--c,x.x,a,x.x,a,m`
+/// Heading/track controller (autopilot) sentence B.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Autopilot {
+    pub status: Status,
+    /// Cycle lock status, for Loran-C.
+    pub cycle_lock: Status,
+    /// Cross track error in nautical miles.
+    pub cross_track_error: Option<f32>,
+    pub steer: Option<SteerDirection>,
+    /// Whether the arrival circle was entered.
+    pub arrival_circle: ArrivalStatus,
+    /// Whether the perpendicular through the destination was passed.
+    pub perpendicular: ArrivalStatus,
+    pub bearing_origin_destination: Option<Bearing>,
+    pub destination: String,
+    pub bearing_to_destination: Option<Bearing>,
+    pub heading_to_steer: Option<Bearing>,
+    pub mode: Option<FaaMode>,
+}
+
+impl Autopilot {
+    pub fn parse(parser: &mut Parser) -> Result<Autopilot, Nmea0183Error> {
+        let status = parser.parse::<Status>()?;
+        let cycle_lock = parser.parse::<Status>()?;
+        let cross_track_error = parser.parse_optional::<f32>()?;
+        let steer = parser.parse_optional::<SteerDirection>()?;
+        parser.skip_if('N');
+        parser.expect(',')?;
+        let arrival_circle = parser.parse::<ArrivalStatus>()?;
+        let perpendicular = parser.parse::<ArrivalStatus>()?;
+        let bearing_origin_destination = parser.parse::<Option<Bearing>>()?;
+        let destination = parser.parse::<String>()?;
+        let bearing_to_destination = parser.parse::<Option<Bearing>>()?;
+        let heading_to_steer = parser.parse::<Option<Bearing>>()?;
+        let mode = parser.parse_optional::<FaaMode>()?;
+        parser.assert_empty()?;
+
+        Ok(Autopilot {
+            status,
+            cycle_lock,
+            cross_track_error,
+            steer,
+            arrival_circle,
+            perpendicular,
+            bearing_origin_destination,
+            destination,
+            bearing_to_destination,
+            heading_to_steer,
+            mode,
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn write(&self, writer: &mut Writer) {
+        writer
+            .field(self.status.to_char())
+            .field(self.cycle_lock.to_char())
+            .float(self.cross_track_error, 2)
+            .optional(self.steer.map(|x| x.to_char()))
+            .field('N')
+            .field(self.arrival_circle.to_char())
+            .field(self.perpendicular.to_char())
+            .bearing(self.bearing_origin_destination)
+            .text(&self.destination)
+            .bearing(self.bearing_to_destination)
+            .bearing(self.heading_to_steer)
+            .optional(self.mode.map(|x| x.to_char()));
+    }
+}
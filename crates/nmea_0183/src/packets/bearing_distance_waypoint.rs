@@ -0,0 +1,71 @@
+#[cfg(feature = "alloc")]
+use crate::writer::Writer;
+use crate::{
+    coordinate::Coordinate, error::Nmea0183Error, faa_mode::FaaMode, parser::Parser, time::Time,
+    String,
+};
+
+/// `hhmmss.ss,llll.ll,a,yyyyy.yy,a,x.x,T,x.x,M,x.x,N,c--c,m`
+/// Bearing and distance to a waypoint, along a great circle.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BearingDistanceWaypoint {
+    pub time: Option<Time>,
+    pub latitude: Option<Coordinate>,
+    pub longitude: Option<Coordinate>,
+    /// Bearing to the waypoint in degrees, true.
+    pub bearing_true: Option<f32>,
+    /// Bearing to the waypoint in degrees, magnetic.
+    pub bearing_magnetic: Option<f32>,
+    /// Distance to the waypoint in nautical miles.
+    pub distance: Option<f32>,
+    pub waypoint: String,
+    pub mode: Option<FaaMode>,
+}
+
+impl BearingDistanceWaypoint {
+    pub fn parse(parser: &mut Parser) -> Result<BearingDistanceWaypoint, Nmea0183Error> {
+        let time = parser.parse_optional::<Time>()?;
+        let latitude = parser.parse_optional::<Coordinate>()?;
+        let longitude = parser.parse_optional::<Coordinate>()?;
+        let bearing_true = parser.parse_optional::<f32>()?;
+        parser.skip_if('T');
+        parser.expect(',')?;
+        let bearing_magnetic = parser.parse_optional::<f32>()?;
+        parser.skip_if('M');
+        parser.expect(',')?;
+        let distance = parser.parse_optional::<f32>()?;
+        parser.skip_if('N');
+        parser.expect(',')?;
+        let waypoint = parser.parse::<String>()?;
+        let mode = parser.parse_optional::<FaaMode>()?;
+        parser.assert_empty()?;
+
+        Ok(BearingDistanceWaypoint {
+            time,
+            latitude,
+            longitude,
+            bearing_true,
+            bearing_magnetic,
+            distance,
+            waypoint,
+            mode,
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn write(&self, writer: &mut Writer) {
+        writer
+            .time(self.time)
+            .latitude(self.latitude)
+            .longitude(self.longitude)
+            .float(self.bearing_true, 1)
+            .field('T')
+            .float(self.bearing_magnetic, 1)
+            .field('M')
+            .float(self.distance, 2)
+            .field('N')
+            .text(&self.waypoint)
+            .optional(self.mode.map(|x| x.to_char()));
+    }
+}
@@ -0,0 +1,47 @@
+#[cfg(feature = "alloc")]
+use crate::writer::Writer;
+use crate::{error::Nmea0183Error, parser::Parser, String};
+
+/// `x.x,T,x.x,M,c--c,c--c`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BearingOriginDestination {
+    /// Bearing from the origin to the destination in degrees, true.
+    pub bearing_true: Option<f32>,
+    /// Bearing from the origin to the destination in degrees, magnetic.
+    pub bearing_magnetic: Option<f32>,
+    pub destination: String,
+    pub origin: String,
+}
+
+impl BearingOriginDestination {
+    pub fn parse(parser: &mut Parser) -> Result<BearingOriginDestination, Nmea0183Error> {
+        let bearing_true = parser.parse_optional::<f32>()?;
+        parser.skip_if('T');
+        parser.expect(',')?;
+        let bearing_magnetic = parser.parse_optional::<f32>()?;
+        parser.skip_if('M');
+        parser.expect(',')?;
+        let destination = parser.parse::<String>()?;
+        let origin = parser.parse::<String>()?;
+        parser.assert_empty()?;
+
+        Ok(BearingOriginDestination {
+            bearing_true,
+            bearing_magnetic,
+            destination,
+            origin,
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn write(&self, writer: &mut Writer) {
+        writer
+            .float(self.bearing_true, 1)
+            .field('T')
+            .float(self.bearing_magnetic, 1)
+            .field('M')
+            .text(&self.destination)
+            .text(&self.origin);
+    }
+}
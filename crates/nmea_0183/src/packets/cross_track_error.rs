@@ -0,0 +1,51 @@
+#[cfg(feature = "alloc")]
+use crate::writer::Writer;
+use crate::{
+    error::Nmea0183Error, faa_mode::FaaMode, navigation::SteerDirection,
+    packets::geographic_position::Status, parser::Parser,
+};
+
+/// `A,A,x.x,a,N,m`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CrossTrackError {
+    pub status: Status,
+    /// Cycle lock status, for Loran-C.
+    pub cycle_lock: Status,
+    /// Cross track error in nautical miles.
+    pub cross_track_error: Option<f32>,
+    pub steer: Option<SteerDirection>,
+    pub mode: Option<FaaMode>,
+}
+
+impl CrossTrackError {
+    pub fn parse(parser: &mut Parser) -> Result<CrossTrackError, Nmea0183Error> {
+        let status = parser.parse::<Status>()?;
+        let cycle_lock = parser.parse::<Status>()?;
+        let cross_track_error = parser.parse_optional::<f32>()?;
+        let steer = parser.parse_optional::<SteerDirection>()?;
+        parser.skip_if('N');
+        parser.skip_if(',');
+        let mode = parser.parse_optional::<FaaMode>()?;
+        parser.assert_empty()?;
+
+        Ok(CrossTrackError {
+            status,
+            cycle_lock,
+            cross_track_error,
+            steer,
+            mode,
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn write(&self, writer: &mut Writer) {
+        writer
+            .field(self.status.to_char())
+            .field(self.cycle_lock.to_char())
+            .float(self.cross_track_error, 2)
+            .optional(self.steer.map(|x| x.to_char()))
+            .field('N')
+            .optional(self.mode.map(|x| x.to_char()));
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{error::Nmea0183Error, parser::Parser};
+
+/// `x.x,f,x.x,M,x.x,F`
+/// Used for both depth below transducer (DBT) and depth below surface (DBS).
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepthBelow {
+    /// Depth in feet.
+    pub feet: Option<f32>,
+    /// Depth in meters.
+    pub meters: Option<f32>,
+    /// Depth in fathoms.
+    pub fathoms: Option<f32>,
+}
+
+impl DepthBelow {
+    pub fn parse(parser: &mut Parser) -> Result<DepthBelow, Nmea0183Error> {
+        let feet = parser.parse_optional::<f32>()?;
+        parser.skip_if('f');
+        parser.expect(',')?;
+        let meters = parser.parse_optional::<f32>()?;
+        parser.skip_if('M');
+        parser.expect(',')?;
+        let fathoms = parser.parse_optional::<f32>()?;
+        parser.skip_if('F');
+        parser.assert_empty()?;
+
+        Ok(DepthBelow {
+            feet,
+            meters,
+            fathoms,
+        })
+    }
+
+    /// Gets the depth in meters, converting from the other units if needed.
+    pub fn depth(&self) -> Option<f32> {
+        self.meters
+            .or(self.feet.map(|x| x * 0.3048))
+            .or(self.fathoms.map(|x| x * 1.8288))
+    }
+}
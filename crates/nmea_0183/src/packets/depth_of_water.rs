@@ -0,0 +1,29 @@
+use crate::{error::Nmea0183Error, parser::Parser};
+
+/// `x.x,x.x,x.x`
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepthOfWater {
+    /// Depth below the transducer in meters.
+    pub depth: Option<f32>,
+    /// Offset from the transducer in meters.
+    /// Positive is the distance to the waterline, negative is the distance to the keel.
+    pub offset: Option<f32>,
+    /// Maximum range scale in use, not sent by older devices.
+    pub max_range: Option<f32>,
+}
+
+impl DepthOfWater {
+    pub fn parse(parser: &mut Parser) -> Result<DepthOfWater, Nmea0183Error> {
+        let depth = parser.parse_optional::<f32>()?;
+        let offset = parser.parse_optional::<f32>()?;
+        let max_range = parser.parse_optional::<f32>()?;
+        parser.assert_empty()?;
+
+        Ok(DepthOfWater {
+            depth,
+            offset,
+            max_range,
+        })
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{error::Nmea0183Error, parser::Parser};
+
+/// `x.x,N,x.x,N,x.x,N,x.x,N`
+/// The ground distances are only sent by NMEA 3.0 and newer devices.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistanceLog {
+    /// Total cumulative distance through water, nautical miles.
+    pub total_water: Option<f32>,
+    /// Distance through water since reset, nautical miles.
+    pub trip_water: Option<f32>,
+    /// Total cumulative distance over ground, nautical miles.
+    pub total_ground: Option<f32>,
+    /// Distance over ground since reset, nautical miles.
+    pub trip_ground: Option<f32>,
+}
+
+impl DistanceLog {
+    pub fn parse(parser: &mut Parser) -> Result<DistanceLog, Nmea0183Error> {
+        let mut distances = [None; 4];
+        for (i, distance) in distances.iter_mut().enumerate() {
+            if i != 0 && parser.expect(',').is_err() {
+                break;
+            }
+
+            *distance = parser.parse_optional::<f32>()?;
+            parser.skip_if('N');
+        }
+        parser.assert_empty()?;
+
+        let [total_water, trip_water, total_ground, trip_ground] = distances;
+        Ok(DistanceLog {
+            total_water,
+            trip_water,
+            total_ground,
+            trip_ground,
+        })
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{
+    coordinate::Coordinate, error::Nmea0183Error, faa_mode::FaaMode, parser::Parser, quick_parser,
+    time::Time,
+};
+
+/// `ddmm.mm,a,dddmm.mm,a,hhmmss.ss,a,m
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeographicPosition {
+    pub latitude: Option<Coordinate>,
+    pub longitude: Option<Coordinate>,
+    pub time: Option<Time>,
+    pub status: Status,
+    pub mode: FaaMode,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Status {
+    DataValid,
+    DataInvalid,
+}
+
+impl GeographicPosition {
+    pub fn parse(parser: &mut Parser) -> Result<GeographicPosition, Nmea0183Error> {
+        let latitude = parser.parse_optional::<Coordinate>()?;
+        let longitude = parser.parse_optional::<Coordinate>()?;
+        let time = parser.parse_optional::<Time>()?;
+        let status = parser.parse::<Status>()?;
+        let mode = parser.parse::<FaaMode>()?;
+        parser.assert_empty()?;
+
+        Ok(GeographicPosition {
+            latitude,
+            longitude,
+            time,
+            status,
+            mode,
+        })
+    }
+}
+
+quick_parser!(Status, {
+    'V' => DataInvalid,
+    'A' => DataValid,
+});
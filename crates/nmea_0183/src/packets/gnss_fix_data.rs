@@ -0,0 +1,109 @@
+use heapless::Vec;
+
+use crate::{
+    coordinate::Coordinate, error::Nmea0183Error, faa_mode::FaaMode,
+    packets::recommended_minimum::NavigationalStatus, parser::Parser, time::Time,
+};
+
+/// `hhmmss.ss,ddmm.mm,a,dddmm.mm,a,c--c,xx,x.x,x.x,x.x,x.x,x.x,a`
+/// The navigational status is only sent by NMEA 4.1 and newer devices.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GnssFixData {
+    pub time: Option<Time>,
+    pub latitude: Option<Coordinate>,
+    pub longitude: Option<Coordinate>,
+    /// One mode per constellation, in the order of [`Constellation::ORDER`].
+    pub modes: Vec<FaaMode, { Constellation::ORDER.len() }>,
+    /// Number of satellites in use.
+    pub satellites: Option<u8>,
+    /// Horizontal dilution of precision.
+    pub hdop: Option<f32>,
+    /// Altitude above mean sea level, meters.
+    pub altitude: Option<f32>,
+    /// Height of the geoid above the WGS84 ellipsoid, meters.
+    pub geoidal_separation: Option<f32>,
+    /// Age of the differential corrections, seconds.
+    pub differential_age: Option<f32>,
+    /// ID of the differential reference station.
+    pub differential_station: Option<u16>,
+    pub navigational_status: Option<NavigationalStatus>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Constellation {
+    Gps,
+    Glonass,
+    Galileo,
+    BeiDou,
+    Qzss,
+    NavIc,
+}
+
+impl Constellation {
+    /// Order of the constellations in the GNS mode indicator.
+    pub const ORDER: [Constellation; 6] = [
+        Constellation::Gps,
+        Constellation::Glonass,
+        Constellation::Galileo,
+        Constellation::BeiDou,
+        Constellation::Qzss,
+        Constellation::NavIc,
+    ];
+}
+
+impl GnssFixData {
+    pub fn parse(parser: &mut Parser) -> Result<GnssFixData, Nmea0183Error> {
+        let time = parser.parse_optional::<Time>()?;
+        let latitude = parser.parse_optional::<Coordinate>()?;
+        let longitude = parser.parse_optional::<Coordinate>()?;
+
+        let mut modes = Vec::new();
+        for chr in parser.take_while(|c| c.is_ascii_alphabetic()) {
+            let chr = *chr as char;
+            let mode = FaaMode::from_char(chr).ok_or(Nmea0183Error::UnexpectedChar(chr))?;
+            modes
+                .push(mode)
+                .map_err(|_| Nmea0183Error::UnexpectedChar(chr))?;
+        }
+        parser.expect(',')?;
+
+        let satellites = parser.parse_optional::<u8>()?;
+        let hdop = parser.parse_optional::<f32>()?;
+        let altitude = parser.parse_optional::<f32>()?;
+        let geoidal_separation = parser.parse_optional::<f32>()?;
+        let differential_age = parser.parse_optional::<f32>()?;
+        let differential_station = parser.parse_optional::<u16>()?;
+        let navigational_status = parser.parse_optional::<NavigationalStatus>()?;
+        parser.assert_empty()?;
+
+        Ok(GnssFixData {
+            time,
+            latitude,
+            longitude,
+            modes,
+            satellites,
+            hdop,
+            altitude,
+            geoidal_separation,
+            differential_age,
+            differential_station,
+            navigational_status,
+        })
+    }
+
+    /// Gets the mode of each constellation.
+    pub fn constellation_modes(&self) -> impl Iterator<Item = (Constellation, FaaMode)> + '_ {
+        Constellation::ORDER
+            .into_iter()
+            .zip(self.modes.iter().copied())
+    }
+
+    /// If any constellation has a valid fix.
+    pub fn is_valid(&self) -> bool {
+        self.modes
+            .iter()
+            .any(|x| !matches!(x, FaaMode::DataNotValid))
+    }
+}
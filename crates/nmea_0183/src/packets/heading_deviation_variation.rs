@@ -0,0 +1,40 @@
+use crate::{error::Nmea0183Error, parser::Parser, variation::Variation};
+
+/// `x.x,x.x,a,x.x,a`
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HeadingDeviationVariation {
+    /// Magnetic sensor heading in degrees.
+    pub heading: Option<f32>,
+    /// Magnetic deviation of the sensor.
+    pub deviation: Option<Variation>,
+    /// Magnetic variation at the current position.
+    pub variation: Option<Variation>,
+}
+
+impl HeadingDeviationVariation {
+    pub fn parse(parser: &mut Parser) -> Result<HeadingDeviationVariation, Nmea0183Error> {
+        let heading = parser.parse_optional::<f32>()?;
+        let deviation = parser.parse::<Option<Variation>>()?;
+        let variation = parser.parse::<Option<Variation>>()?;
+        parser.assert_empty()?;
+
+        Ok(HeadingDeviationVariation {
+            heading,
+            deviation,
+            variation,
+        })
+    }
+
+    /// The magnetic heading, corrected for the sensor deviation.
+    pub fn magnetic_heading(&self) -> Option<f32> {
+        let deviation = self.deviation.map_or(0.0, |x| x.degree);
+        self.heading.map(|x| {
+            let heading = (x + deviation) % 360.0;
+            match heading < 0.0 {
+                true => heading + 360.0,
+                false => heading,
+            }
+        })
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{error::Nmea0183Error, parser::Parser};
+
+/// `x.x,M`
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MagneticHeading {
+    /// Heading in degrees, magnetic.
+    pub heading: Option<f32>,
+}
+
+impl MagneticHeading {
+    pub fn parse(parser: &mut Parser) -> Result<MagneticHeading, Nmea0183Error> {
+        let heading = parser.parse_optional::<f32>()?;
+        parser.skip_if('M');
+        parser.assert_empty()?;
+
+        Ok(MagneticHeading { heading })
+    }
+}
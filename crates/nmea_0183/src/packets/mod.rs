@@ -0,0 +1,31 @@
+pub mod active_satellites;
+pub mod autopilot;
+pub mod bearing_distance_waypoint;
+pub mod bearing_origin_destination;
+pub mod cross_track_error;
+pub mod depth_below;
+pub mod depth_of_water;
+pub mod distance_log;
+pub mod geographic_position;
+pub mod gnss_fix_data;
+pub mod ground_speed;
+pub mod heading_deviation_variation;
+pub mod magnetic_heading;
+pub mod pseudorange_error_statistics;
+pub mod rate_of_turn;
+pub mod recommended_minimum;
+pub mod recommended_navigation;
+pub mod route;
+pub mod satellite_fault_detection;
+pub mod satellites_in_view;
+pub mod text;
+pub mod time_date;
+pub mod transducer_measurement;
+pub mod true_heading;
+pub mod true_heading_status;
+pub mod water_speed_heading;
+pub mod water_temperature;
+pub mod waypoint_location;
+pub mod wind_angle;
+pub mod wind_direction_speed;
+pub mod wind_speed_angle;
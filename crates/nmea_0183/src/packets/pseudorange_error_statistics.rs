@@ -0,0 +1,48 @@
+use crate::{error::Nmea0183Error, parser::Parser, time::Time};
+
+/// `hhmmss.ss,x.x,x.x,x.x,x.x,x.x,x.x,x.x`
+/// All errors are one standard deviation, in meters.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PseudorangeErrorStatistics {
+    pub time: Option<Time>,
+    /// RMS value of the standard deviation of the range inputs.
+    pub rms: Option<f32>,
+    /// Standard deviation of the semi-major axis of the error ellipse.
+    pub semi_major: Option<f32>,
+    /// Standard deviation of the semi-minor axis of the error ellipse.
+    pub semi_minor: Option<f32>,
+    /// Orientation of the semi-major axis, in degrees from true north.
+    pub orientation: Option<f32>,
+    /// Standard deviation of the latitude error.
+    pub latitude_error: Option<f32>,
+    /// Standard deviation of the longitude error.
+    pub longitude_error: Option<f32>,
+    /// Standard deviation of the altitude error.
+    pub altitude_error: Option<f32>,
+}
+
+impl PseudorangeErrorStatistics {
+    pub fn parse(parser: &mut Parser) -> Result<PseudorangeErrorStatistics, Nmea0183Error> {
+        let time = parser.parse_optional::<Time>()?;
+        let rms = parser.parse_optional::<f32>()?;
+        let semi_major = parser.parse_optional::<f32>()?;
+        let semi_minor = parser.parse_optional::<f32>()?;
+        let orientation = parser.parse_optional::<f32>()?;
+        let latitude_error = parser.parse_optional::<f32>()?;
+        let longitude_error = parser.parse_optional::<f32>()?;
+        let altitude_error = parser.parse_optional::<f32>()?;
+        parser.assert_empty()?;
+
+        Ok(PseudorangeErrorStatistics {
+            time,
+            rms,
+            semi_major,
+            semi_minor,
+            orientation,
+            latitude_error,
+            longitude_error,
+            altitude_error,
+        })
+    }
+}
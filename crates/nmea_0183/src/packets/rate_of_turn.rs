@@ -0,0 +1,20 @@
+use crate::{error::Nmea0183Error, packets::geographic_position::Status, parser::Parser};
+
+/// `x.x,A`
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RateOfTurn {
+    /// Rate of turn in degrees per minute, negative values are turning to port.
+    pub rate: Option<f32>,
+    pub status: Status,
+}
+
+impl RateOfTurn {
+    pub fn parse(parser: &mut Parser) -> Result<RateOfTurn, Nmea0183Error> {
+        let rate = parser.parse_optional::<f32>()?;
+        let status = parser.parse::<Status>()?;
+        parser.assert_empty()?;
+
+        Ok(RateOfTurn { rate, status })
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    coordinate::Coordinate,
+    error::Nmea0183Error,
+    faa_mode::FaaMode,
+    packets::geographic_position::Status,
+    parser::Parser,
+    quick_parser,
+    time::{Date, Time},
+    variation::Variation,
+};
+
+/// `hhmmss.ss,A,ddmm.mm,a,dddmm.mm,a,x.x,x.x,ddmmyy,x.x,a,m,s`
+/// The mode is only sent by NMEA 2.3 and newer devices, the navigational status by NMEA 4.1 and newer.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecommendedMinimum {
+    pub time: Option<Time>,
+    pub status: Status,
+    pub latitude: Option<Coordinate>,
+    pub longitude: Option<Coordinate>,
+    /// Speed over ground, knots.
+    pub speed_knots: Option<f32>,
+    /// Course over ground, true.
+    pub course_true: Option<f32>,
+    pub date: Option<Date>,
+    /// Magnetic variation.
+    pub variation: Option<Variation>,
+    pub mode: Option<FaaMode>,
+    pub navigational_status: Option<NavigationalStatus>,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NavigationalStatus {
+    Safe,
+    Caution,
+    Unsafe,
+    NotValid,
+}
+
+impl RecommendedMinimum {
+    pub fn parse(parser: &mut Parser) -> Result<RecommendedMinimum, Nmea0183Error> {
+        let time = parser.parse_optional::<Time>()?;
+        let status = parser.parse::<Status>()?;
+        let latitude = parser.parse_optional::<Coordinate>()?;
+        let longitude = parser.parse_optional::<Coordinate>()?;
+        let speed_knots = parser.parse_optional::<f32>()?;
+        let course_true = parser.parse_optional::<f32>()?;
+        let date = parser.parse_optional::<Date>()?;
+        let variation = parser.parse::<Option<Variation>>()?;
+        let mode = parser.parse_optional::<FaaMode>()?;
+        let navigational_status = parser.parse_optional::<NavigationalStatus>()?;
+        parser.assert_empty()?;
+
+        Ok(RecommendedMinimum {
+            time,
+            status,
+            latitude,
+            longitude,
+            speed_knots,
+            course_true,
+            date,
+            variation,
+            mode,
+            navigational_status,
+        })
+    }
+}
+
+quick_parser!(NavigationalStatus, {
+    'S' => Safe,
+    'C' => Caution,
+    'U' => Unsafe,
+    'V' => NotValid,
+});
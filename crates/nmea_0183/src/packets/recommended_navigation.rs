@@ -0,0 +1,83 @@
+#[cfg(feature = "alloc")]
+use crate::writer::Writer;
+use crate::{
+    coordinate::Coordinate,
+    error::Nmea0183Error,
+    faa_mode::FaaMode,
+    navigation::{ArrivalStatus, SteerDirection},
+    packets::geographic_position::Status,
+    parser::Parser,
+    String,
+};
+
+/// `A,x.x,a,c--c,c--c,llll.ll,a,yyyyy.yy,a,x.x,x.x,x.x,A,m`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecommendedNavigation {
+    pub status: Status,
+    /// Cross track error in nautical miles.
+    pub cross_track_error: Option<f32>,
+    pub steer: Option<SteerDirection>,
+    pub origin: String,
+    pub destination: String,
+    pub destination_latitude: Option<Coordinate>,
+    pub destination_longitude: Option<Coordinate>,
+    /// Range to the destination in nautical miles.
+    pub range: Option<f32>,
+    /// Bearing to the destination in degrees, true.
+    pub bearing: Option<f32>,
+    /// Velocity towards the destination in knots.
+    pub closing_velocity: Option<f32>,
+    pub arrival: ArrivalStatus,
+    pub mode: Option<FaaMode>,
+}
+
+impl RecommendedNavigation {
+    pub fn parse(parser: &mut Parser) -> Result<RecommendedNavigation, Nmea0183Error> {
+        let status = parser.parse::<Status>()?;
+        let cross_track_error = parser.parse_optional::<f32>()?;
+        let steer = parser.parse_optional::<SteerDirection>()?;
+        let origin = parser.parse::<String>()?;
+        let destination = parser.parse::<String>()?;
+        let destination_latitude = parser.parse_optional::<Coordinate>()?;
+        let destination_longitude = parser.parse_optional::<Coordinate>()?;
+        let range = parser.parse_optional::<f32>()?;
+        let bearing = parser.parse_optional::<f32>()?;
+        let closing_velocity = parser.parse_optional::<f32>()?;
+        let arrival = parser.parse::<ArrivalStatus>()?;
+        let mode = parser.parse_optional::<FaaMode>()?;
+        parser.assert_empty()?;
+
+        Ok(RecommendedNavigation {
+            status,
+            cross_track_error,
+            steer,
+            origin,
+            destination,
+            destination_latitude,
+            destination_longitude,
+            range,
+            bearing,
+            closing_velocity,
+            arrival,
+            mode,
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn write(&self, writer: &mut Writer) {
+        writer
+            .field(self.status.to_char())
+            .float(self.cross_track_error, 2)
+            .optional(self.steer.map(|x| x.to_char()))
+            .text(&self.origin)
+            .text(&self.destination)
+            .latitude(self.destination_latitude)
+            .longitude(self.destination_longitude)
+            .float(self.range, 1)
+            .float(self.bearing, 1)
+            .float(self.closing_velocity, 1)
+            .field(self.arrival.to_char())
+            .optional(self.mode.map(|x| x.to_char()));
+    }
+}
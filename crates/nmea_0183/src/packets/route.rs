@@ -0,0 +1,69 @@
+#[cfg(feature = "alloc")]
+use crate::writer::Writer;
+use crate::{error::Nmea0183Error, parser::Parser, quick_parser, List, String, TryPush};
+
+/// Most waypoints in a sentence, each takes at least two characters.
+pub const MAX_WAYPOINTS: usize = 40;
+
+/// `x.x,x.x,a,c--c,c--c,...`
+/// One part of a route, long routes are split over multiple sentences.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Route {
+    /// Number of sentences in the route.
+    pub total: u8,
+    /// Number of this sentence, starting at 1.
+    pub number: u8,
+    pub mode: RouteMode,
+    pub route: String,
+    pub waypoints: List<String, MAX_WAYPOINTS>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RouteMode {
+    /// All waypoints of the route.
+    Complete,
+    /// The waypoints still to be navigated to, starting with the origin of the current leg.
+    Working,
+}
+
+quick_parser!(RouteMode, {
+    'c' => Complete,
+    'w' => Working,
+});
+
+impl Route {
+    pub fn parse(parser: &mut Parser) -> Result<Route, Nmea0183Error> {
+        let total = parser.parse::<u8>()?;
+        let number = parser.parse::<u8>()?;
+        let mode = parser.parse::<RouteMode>()?;
+        let route = parser.parse::<String>()?;
+
+        let mut waypoints = List::new();
+        while parser.peek().is_some() {
+            waypoints.try_push(parser.parse::<String>()?)?;
+        }
+        parser.assert_empty()?;
+
+        Ok(Route {
+            total,
+            number,
+            mode,
+            route,
+            waypoints,
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn write(&self, writer: &mut Writer) {
+        writer
+            .field(self.total)
+            .field(self.number)
+            .field(self.mode.to_char())
+            .text(&self.route);
+        for waypoint in self.waypoints.iter() {
+            writer.text(waypoint);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{error::Nmea0183Error, parser::Parser, time::Time};
+
+/// `hhmmss.ss,x.x,x.x,x.x,xx,x.x,x.x,x.x,h,h`
+/// The system and signal IDs are only sent by NMEA 4.1 and newer devices.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SatelliteFaultDetection {
+    pub time: Option<Time>,
+    /// Expected error in latitude, meters.
+    pub latitude_error: Option<f32>,
+    /// Expected error in longitude, meters.
+    pub longitude_error: Option<f32>,
+    /// Expected error in altitude, meters.
+    pub altitude_error: Option<f32>,
+    /// ID of the most likely failed satellite, if one was detected.
+    pub failed_satellite: Option<u8>,
+    /// Probability of missed detection of the failed satellite.
+    pub missed_probability: Option<f32>,
+    /// Estimate of the bias on the failed satellite, meters.
+    pub bias: Option<f32>,
+    /// Standard deviation of the bias estimate.
+    pub bias_deviation: Option<f32>,
+    /// GNSS system ID of the failed satellite.
+    pub system_id: Option<u8>,
+    /// GNSS signal ID of the failed satellite.
+    pub signal_id: Option<u8>,
+}
+
+impl SatelliteFaultDetection {
+    pub fn parse(parser: &mut Parser) -> Result<SatelliteFaultDetection, Nmea0183Error> {
+        let time = parser.parse_optional::<Time>()?;
+        let latitude_error = parser.parse_optional::<f32>()?;
+        let longitude_error = parser.parse_optional::<f32>()?;
+        let altitude_error = parser.parse_optional::<f32>()?;
+        let failed_satellite = parser.parse_optional::<u8>()?;
+        let missed_probability = parser.parse_optional::<f32>()?;
+        let bias = parser.parse_optional::<f32>()?;
+        let bias_deviation = parser.parse_optional::<f32>()?;
+        let system_id = parser.parse_optional::<u8>()?;
+        let signal_id = parser.parse_optional::<u8>()?;
+        parser.assert_empty()?;
+
+        Ok(SatelliteFaultDetection {
+            time,
+            latitude_error,
+            longitude_error,
+            altitude_error,
+            failed_satellite,
+            missed_probability,
+            bias,
+            bias_deviation,
+            system_id,
+            signal_id,
+        })
+    }
+}
@@ -1,9 +1,15 @@
-use crate::nmea_0183::{
+use heapless::Vec;
+
+use crate::{
     error::Nmea0183Error,
     parser::{FromParser, Parser},
 };
 
+/// Most satellites a single sentence can contain.
+pub const MAX_SATELLITES: usize = 4;
+
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SatellitesInView {
     /// Total number of messages of this type in this cycle.
     pub total_in_group: u8,
@@ -12,10 +18,11 @@ pub struct SatellitesInView {
     /// Total number of satellites in view.
     pub in_view: u16,
     /// The satellites contained in this message (total_in_group).
-    pub satellites: Box<[Satellite]>,
+    pub satellites: Vec<Satellite, MAX_SATELLITES>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Satellite {
     /// The id of this satellite.
     pub id: u8,
@@ -28,24 +35,20 @@ pub struct Satellite {
 }
 
 impl SatellitesInView {
-    pub fn parse(sentence: &[u8]) -> Result<SatellitesInView, Nmea0183Error> {
-        let mut parser = Parser::new(sentence).take_on_parse(',');
-
+    pub fn parse(parser: &mut Parser) -> Result<SatellitesInView, Nmea0183Error> {
         let total_in_group = parser.parse::<u8>()?;
         let sentence_number = parser.parse::<u8>()?;
         let in_view = parser.parse::<u16>()?;
 
         let mut satellites = Vec::new();
-        for _ in 0..4 {
-            match parser.parse::<Satellite>() {
-                Ok(satellite) => satellites.push(satellite),
-                Err(Nmea0183Error::Incomplete) => break,
-                Err(err) => return Err(err),
-            }
+        while !satellites.is_full() {
+            let Some(satellite) = parser.parse_optional::<Satellite>()? else {
+                break;
+            };
+            // Can't fail, as it isn't full
+            let _ = satellites.push(satellite);
         }
 
-        let satellites = satellites.into_boxed_slice();
-
         Ok(SatellitesInView {
             total_in_group,
             sentence_number,
@@ -56,11 +59,14 @@ impl SatellitesInView {
 }
 
 impl<'a> FromParser<'a> for Satellite {
+    const NAME: &'static str = "satellite";
+    const FIELDS: usize = 4;
+
     fn parse(parser: &mut Parser<'a>) -> Result<Self, Nmea0183Error> {
-        let id = parser.parse::<u8>().ok().ok_or(Nmea0183Error::Incomplete)?;
-        let elevation = parser.parse::<i8>().ok();
-        let azimuth = parser.parse::<u16>().ok();
-        let snr = parser.parse::<u8>().ok();
+        let id = parser.parse::<u8>()?;
+        let elevation = parser.parse_optional::<i8>()?;
+        let azimuth = parser.parse_optional::<u16>()?;
+        let snr = parser.parse_optional::<u8>()?;
 
         Ok(Satellite {
             id,
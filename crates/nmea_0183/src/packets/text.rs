@@ -1,6 +1,7 @@
-use crate::nmea_0183::{error::Nmea0183Error, parser::Parser};
+use crate::{error::Nmea0183Error, parser::Parser, String};
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Text {
     pub total_sentences: u8,
     pub sentence_number: u8,
@@ -9,9 +10,7 @@ pub struct Text {
 }
 
 impl Text {
-    pub fn parse(sentence: &[u8]) -> Result<Text, Nmea0183Error> {
-        let mut parser = Parser::new(sentence).take_on_parse(',');
-
+    pub fn parse(parser: &mut Parser) -> Result<Text, Nmea0183Error> {
         let total_sentences = parser.parse::<u8>()?;
         let sentence_number = parser.parse::<u8>()?;
         let text_identifier = parser.parse::<u8>()?;
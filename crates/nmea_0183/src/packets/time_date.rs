@@ -0,0 +1,51 @@
+use chrono::FixedOffset;
+
+use crate::{
+    error::Nmea0183Error,
+    parser::Parser,
+    time::{Date, Time},
+};
+
+/// `hhmmss.ss,xx,xx,xxxx,xx,xx`
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeDate {
+    pub time: Option<Time>,
+    pub date: Option<Date>,
+    /// Local zone hours, -13 to 13.
+    pub local_zone_hours: Option<i8>,
+    /// Local zone minutes, 0 to 59.
+    pub local_zone_minutes: Option<u8>,
+}
+
+impl TimeDate {
+    pub fn parse(parser: &mut Parser) -> Result<TimeDate, Nmea0183Error> {
+        let time = parser.parse_optional::<Time>()?;
+        let day = parser.parse_optional::<u8>()?;
+        let month = parser.parse_optional::<u8>()?;
+        let year = parser.parse_optional::<u16>()?;
+        let local_zone_hours = parser.parse_optional::<i8>()?;
+        let local_zone_minutes = parser.parse_optional::<u8>()?;
+        parser.assert_empty()?;
+
+        let date = match (day, month, year) {
+            (Some(day), Some(month), Some(year)) => Some(Date { day, month, year }),
+            _ => None,
+        };
+
+        Ok(TimeDate {
+            time,
+            date,
+            local_zone_hours,
+            local_zone_minutes,
+        })
+    }
+
+    /// Gets the offset of the local time zone from UTC.
+    pub fn local_offset(&self) -> Option<FixedOffset> {
+        let hours = i32::from(self.local_zone_hours?);
+        let minutes = i32::from(self.local_zone_minutes.unwrap_or(0));
+        let seconds = (hours.abs() * 60 + minutes) * 60;
+        FixedOffset::east_opt(if hours < 0 { -seconds } else { seconds })
+    }
+}
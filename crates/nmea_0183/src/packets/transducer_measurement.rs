@@ -0,0 +1,140 @@
+use crate::{
+    error::Nmea0183Error,
+    parser::{FromParser, Parser},
+    List, String, TryPush,
+};
+
+/// Most measurements in a sentence, each takes at least four characters.
+pub const MAX_MEASUREMENTS: usize = 16;
+
+/// `a,x.x,a,c--c,...`
+/// Any number of measurements, each with a type, value, unit and name.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransducerMeasurements {
+    pub measurements: List<Measurement, MAX_MEASUREMENTS>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Measurement {
+    pub kind: TransducerType,
+    pub value: Option<f32>,
+    /// Unit of the value, its meaning depends on the transducer type.
+    pub unit: Option<char>,
+    /// Name of the transducer, may be empty.
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TransducerType {
+    /// Degrees (`D`), negative is port or down.
+    Angle,
+    /// Degrees Celsius (`C`).
+    Temperature,
+    /// Meters (`M`).
+    Displacement,
+    /// Hertz (`H`).
+    Frequency,
+    /// Percent (`P`).
+    Humidity,
+    /// Amperes (`A`).
+    Current,
+    /// Bars (`B`) or pascals (`P`).
+    Pressure,
+    /// Revolutions per minute (`R`).
+    Tachometer,
+    /// Volts (`V`).
+    Voltage,
+    /// Cubic meters (`M`).
+    Volume,
+    /// Parts per thousand (`S`).
+    Salinity,
+    /// No unit.
+    Switch,
+    /// No unit.
+    Generic,
+    Other(char),
+}
+
+impl TransducerMeasurements {
+    pub fn parse(parser: &mut Parser) -> Result<TransducerMeasurements, Nmea0183Error> {
+        let mut measurements = List::new();
+        while parser.peek().is_some() {
+            measurements.try_push(parser.parse::<Measurement>()?)?;
+        }
+
+        Ok(TransducerMeasurements { measurements })
+    }
+}
+
+impl<'a> FromParser<'a> for Measurement {
+    const NAME: &'static str = "measurement";
+    const FIELDS: usize = 4;
+
+    fn parse(parser: &mut Parser<'a>) -> Result<Self, Nmea0183Error> {
+        let kind = TransducerType::from_char(parser.next()?);
+        parser.expect(',')?;
+        let value = parser.parse_optional::<f32>()?;
+        let unit = match parser.skip_if(',') {
+            true => None,
+            false => {
+                let unit = parser.next()?;
+                if parser.peek().is_some() {
+                    parser.expect(',')?;
+                }
+                Some(unit)
+            }
+        };
+        // Parsed directly so the separator before the next measurement is left for the caller
+        let name = String::parse(parser)?;
+
+        Ok(Measurement {
+            kind,
+            value,
+            unit,
+            name,
+        })
+    }
+}
+
+impl TransducerType {
+    pub fn from_char(chr: char) -> Self {
+        match chr {
+            'A' => Self::Angle,
+            'C' => Self::Temperature,
+            'D' => Self::Displacement,
+            'F' => Self::Frequency,
+            'H' => Self::Humidity,
+            'I' => Self::Current,
+            'P' => Self::Pressure,
+            'T' => Self::Tachometer,
+            'U' => Self::Voltage,
+            'V' => Self::Volume,
+            'L' => Self::Salinity,
+            'S' => Self::Switch,
+            'G' => Self::Generic,
+            _ => Self::Other(chr),
+        }
+    }
+
+    /// Gets the symbol for a unit of this transducer type.
+    #[cfg(feature = "alloc")]
+    pub fn unit_symbol(&self, unit: char) -> String {
+        match (self, unit) {
+            (Self::Angle, 'D') => "°".into(),
+            (Self::Temperature, 'C') => "°C".into(),
+            (Self::Displacement | Self::Volume, 'M') => "m".into(),
+            (Self::Frequency, 'H') => "Hz".into(),
+            (Self::Humidity, 'P') => "%".into(),
+            (Self::Current, 'A') => "A".into(),
+            (Self::Pressure, 'B') => "bar".into(),
+            (Self::Pressure, 'P') => "Pa".into(),
+            (Self::Tachometer, 'R') => "RPM".into(),
+            (Self::Voltage, 'V') => "V".into(),
+            (Self::Salinity, 'S') => "‰".into(),
+            _ => unit.into(),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{error::Nmea0183Error, parser::Parser};
+
+/// `x.x,T`
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrueHeading {
+    /// Heading in degrees, true.
+    pub heading: Option<f32>,
+}
+
+impl TrueHeading {
+    pub fn parse(parser: &mut Parser) -> Result<TrueHeading, Nmea0183Error> {
+        let heading = parser.parse_optional::<f32>()?;
+        parser.skip_if('T');
+        parser.assert_empty()?;
+
+        Ok(TrueHeading { heading })
+    }
+}
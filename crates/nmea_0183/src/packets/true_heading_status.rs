@@ -0,0 +1,21 @@
+use crate::{error::Nmea0183Error, faa_mode::FaaMode, parser::Parser};
+
+/// `x.x,a`
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrueHeadingStatus {
+    /// Heading in degrees, true.
+    pub heading: Option<f32>,
+    /// Mode indicator, only `A`, `E`, `M`, `S` and `V` (`DataNotValid`) are used.
+    pub mode: FaaMode,
+}
+
+impl TrueHeadingStatus {
+    pub fn parse(parser: &mut Parser) -> Result<TrueHeadingStatus, Nmea0183Error> {
+        let heading = parser.parse_optional::<f32>()?;
+        let mode = parser.parse::<FaaMode>()?;
+        parser.assert_empty()?;
+
+        Ok(TrueHeadingStatus { heading, mode })
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{error::Nmea0183Error, parser::Parser};
+
+/// `x.x,T,x.x,M,x.x,N,x.x,K`
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WaterSpeedHeading {
+    /// Heading in degrees, true.
+    pub heading_true: Option<f32>,
+    /// Heading in degrees, magnetic.
+    pub heading_magnetic: Option<f32>,
+    /// Speed through water, knots.
+    pub speed_knots: Option<f32>,
+    /// Speed through water, kilometers per hour.
+    pub speed_kph: Option<f32>,
+}
+
+impl WaterSpeedHeading {
+    pub fn parse(parser: &mut Parser) -> Result<WaterSpeedHeading, Nmea0183Error> {
+        let heading_true = parser.parse_optional::<f32>()?;
+        parser.skip_if('T');
+        parser.expect(',')?;
+        let heading_magnetic = parser.parse_optional::<f32>()?;
+        parser.skip_if('M');
+        parser.expect(',')?;
+        let speed_knots = parser.parse_optional::<f32>()?;
+        parser.skip_if('N');
+        parser.expect(',')?;
+        let speed_kph = parser.parse_optional::<f32>()?;
+        parser.skip_if('K');
+        parser.assert_empty()?;
+
+        Ok(WaterSpeedHeading {
+            heading_true,
+            heading_magnetic,
+            speed_knots,
+            speed_kph,
+        })
+    }
+
+    /// Gets the speed through water in knots, converting from kilometers per hour if needed.
+    pub fn speed(&self) -> Option<f32> {
+        self.speed_knots.or(self.speed_kph.map(|x| x / 1.852))
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{error::Nmea0183Error, parser::Parser};
+
+/// `x.x,C`
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WaterTemperature {
+    /// Water temperature in degrees Celsius.
+    pub temperature: Option<f32>,
+}
+
+impl WaterTemperature {
+    pub fn parse(parser: &mut Parser) -> Result<WaterTemperature, Nmea0183Error> {
+        let temperature = parser.parse_optional::<f32>()?;
+        parser.skip_if('C');
+        parser.assert_empty()?;
+
+        Ok(WaterTemperature { temperature })
+    }
+}
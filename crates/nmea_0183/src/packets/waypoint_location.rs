@@ -0,0 +1,35 @@
+#[cfg(feature = "alloc")]
+use crate::writer::Writer;
+use crate::{coordinate::Coordinate, error::Nmea0183Error, parser::Parser, String};
+
+/// `llll.ll,a,yyyyy.yy,a,c--c`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WaypointLocation {
+    pub latitude: Coordinate,
+    pub longitude: Coordinate,
+    pub waypoint: String,
+}
+
+impl WaypointLocation {
+    pub fn parse(parser: &mut Parser) -> Result<WaypointLocation, Nmea0183Error> {
+        let latitude = parser.parse::<Coordinate>()?;
+        let longitude = parser.parse::<Coordinate>()?;
+        let waypoint = parser.parse::<String>()?;
+        parser.assert_empty()?;
+
+        Ok(WaypointLocation {
+            latitude,
+            longitude,
+            waypoint,
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn write(&self, writer: &mut Writer) {
+        writer
+            .latitude(Some(self.latitude))
+            .longitude(Some(self.longitude))
+            .text(&self.waypoint);
+    }
+}
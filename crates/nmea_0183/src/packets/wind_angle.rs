@@ -0,0 +1,49 @@
+use crate::{error::Nmea0183Error, parser::Parser};
+
+/// `x.x,a,x.x,N,x.x,M,x.x,K`
+/// Used for both relative (VWR) and true (VWT) wind.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindAngle {
+    /// Wind angle in degrees from the bow, 0 to 180.
+    /// Negative values are from the left (port).
+    pub angle: Option<f32>,
+    /// Wind speed in knots.
+    pub speed_knots: Option<f32>,
+    /// Wind speed in meters per second.
+    pub speed_mps: Option<f32>,
+    /// Wind speed in kilometers per hour.
+    pub speed_kph: Option<f32>,
+}
+
+impl WindAngle {
+    pub fn parse(parser: &mut Parser) -> Result<WindAngle, Nmea0183Error> {
+        let angle = parser.parse_optional::<f32>()?;
+        let left = parser.skip_if('L');
+        parser.skip_if('R');
+        parser.expect(',')?;
+        let speed_knots = parser.parse_optional::<f32>()?;
+        parser.skip_if('N');
+        parser.expect(',')?;
+        let speed_mps = parser.parse_optional::<f32>()?;
+        parser.skip_if('M');
+        parser.expect(',')?;
+        let speed_kph = parser.parse_optional::<f32>()?;
+        parser.skip_if('K');
+        parser.assert_empty()?;
+
+        Ok(WindAngle {
+            angle: angle.map(|x| if left { -x } else { x }),
+            speed_knots,
+            speed_mps,
+            speed_kph,
+        })
+    }
+
+    /// Gets the wind speed in knots, converting from the other units if needed.
+    pub fn speed(&self) -> Option<f32> {
+        self.speed_knots
+            .or(self.speed_mps.map(|x| x * 1.943_844))
+            .or(self.speed_kph.map(|x| x / 1.852))
+    }
+}
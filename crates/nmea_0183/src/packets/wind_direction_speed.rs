@@ -0,0 +1,44 @@
+use crate::{error::Nmea0183Error, parser::Parser};
+
+/// `x.x,T,x.x,M,x.x,N,x.x,M`
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindDirectionSpeed {
+    /// Direction the wind is blowing from, in degrees true.
+    pub direction_true: Option<f32>,
+    /// Direction the wind is blowing from, in degrees magnetic.
+    pub direction_magnetic: Option<f32>,
+    /// Wind speed in knots.
+    pub speed_knots: Option<f32>,
+    /// Wind speed in meters per second.
+    pub speed_mps: Option<f32>,
+}
+
+impl WindDirectionSpeed {
+    pub fn parse(parser: &mut Parser) -> Result<WindDirectionSpeed, Nmea0183Error> {
+        let direction_true = parser.parse_optional::<f32>()?;
+        parser.skip_if('T');
+        parser.expect(',')?;
+        let direction_magnetic = parser.parse_optional::<f32>()?;
+        parser.skip_if('M');
+        parser.expect(',')?;
+        let speed_knots = parser.parse_optional::<f32>()?;
+        parser.skip_if('N');
+        parser.expect(',')?;
+        let speed_mps = parser.parse_optional::<f32>()?;
+        parser.skip_if('M');
+        parser.assert_empty()?;
+
+        Ok(WindDirectionSpeed {
+            direction_true,
+            direction_magnetic,
+            speed_knots,
+            speed_mps,
+        })
+    }
+
+    /// Gets the wind speed in knots, converting from meters per second if needed.
+    pub fn speed(&self) -> Option<f32> {
+        self.speed_knots.or(self.speed_mps.map(|x| x * 1.943_844))
+    }
+}
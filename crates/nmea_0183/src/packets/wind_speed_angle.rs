@@ -0,0 +1,82 @@
+use crate::{
+    error::Nmea0183Error, packets::geographic_position::Status, parser::Parser, quick_parser,
+};
+
+/// `x.x,a,x.x,a,a`
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindSpeedAngle {
+    /// Wind angle in degrees from the bow, 0 to 359.
+    pub angle: Option<f32>,
+    /// If the angle and speed are relative (apparent) or true.
+    pub reference: WindReference,
+    /// Wind speed in `unit`.
+    pub speed: Option<f32>,
+    pub unit: Option<SpeedUnit>,
+    pub status: Status,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindReference {
+    /// Apparent wind, as measured on the moving vessel.
+    Relative,
+    /// True wind, calculated by the instrument.
+    True,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpeedUnit {
+    KilometersPerHour,
+    MetersPerSecond,
+    Knots,
+    MilesPerHour,
+}
+
+impl WindSpeedAngle {
+    pub fn parse(parser: &mut Parser) -> Result<WindSpeedAngle, Nmea0183Error> {
+        let angle = parser.parse_optional::<f32>()?;
+        let reference = parser.parse::<WindReference>()?;
+        let speed = parser.parse_optional::<f32>()?;
+        let unit = parser.parse_optional::<SpeedUnit>()?;
+        let status = parser.parse::<Status>()?;
+        parser.assert_empty()?;
+
+        Ok(WindSpeedAngle {
+            angle,
+            reference,
+            speed,
+            unit,
+            status,
+        })
+    }
+
+    /// Gets the wind speed in knots.
+    pub fn speed_knots(&self) -> Option<f32> {
+        Some(self.unit?.to_knots(self.speed?))
+    }
+}
+
+impl SpeedUnit {
+    pub fn to_knots(&self, speed: f32) -> f32 {
+        match self {
+            SpeedUnit::KilometersPerHour => speed / 1.852,
+            SpeedUnit::MetersPerSecond => speed * 1.943_844,
+            SpeedUnit::Knots => speed,
+            SpeedUnit::MilesPerHour => speed * 0.868_976,
+        }
+    }
+}
+
+quick_parser!(WindReference, {
+    'R' => Relative,
+    'T' => True,
+});
+
+quick_parser!(SpeedUnit, {
+    'K' => KilometersPerHour,
+    'M' => MetersPerSecond,
+    'N' => Knots,
+    'S' => MilesPerHour,
+});
@@ -0,0 +1,431 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+use core::{fmt::Write, str};
+
+#[cfg(feature = "alloc")]
+use super::error::FieldError;
+use super::{error::Nmea0183Error, String};
+
+pub struct Parser<'a> {
+    data: &'a [u8],
+    index: usize,
+
+    take_on_parse: Option<char>,
+    /// Byte offset of the data in the line, so errors point into the whole line.
+    #[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+    offset: usize,
+    /// Invalid optional fields are skipped instead of failing the parse.
+    lenient: bool,
+    /// Every invalid field, only collected in lenient mode.
+    #[cfg(feature = "alloc")]
+    diagnostics: Vec<FieldError>,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            index: 0,
+            take_on_parse: None,
+            offset: 0,
+            lenient: false,
+            #[cfg(feature = "alloc")]
+            diagnostics: Vec::new(),
+        }
+    }
+
+    pub fn take_on_parse(self, c: char) -> Self {
+        Self {
+            take_on_parse: Some(c),
+            ..self
+        }
+    }
+
+    pub fn with_offset(self, offset: usize) -> Self {
+        Self { offset, ..self }
+    }
+
+    pub fn lenient(self, lenient: bool) -> Self {
+        Self { lenient, ..self }
+    }
+
+    /// Takes the invalid fields found so far.
+    #[cfg(feature = "alloc")]
+    pub fn take_diagnostics(&mut self) -> Vec<FieldError> {
+        core::mem::take(&mut self.diagnostics)
+    }
+
+    pub fn take_while(&mut self, c: fn(char) -> bool) -> &'a [u8] {
+        let start = self.index;
+        while self.peek().is_some_and(c) {
+            self.index += 1;
+        }
+
+        &self.data[start..self.index]
+    }
+
+    pub fn assert_empty(&mut self) -> Result<(), Nmea0183Error> {
+        if self.index == self.data.len() {
+            return Ok(());
+        }
+
+        let err = self.field_error(
+            self.index,
+            self.data.len(),
+            "end of sentence",
+            Nmea0183Error::RemainingData,
+        );
+        match self.lenient {
+            true => Ok(()),
+            false => Err(err),
+        }
+    }
+
+    // Unlike `Iterator::next`, reading past the end is an error
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Result<char, Nmea0183Error> {
+        if self.index >= self.data.len() {
+            return Err(Nmea0183Error::Incomplete);
+        }
+
+        let out = self.data[self.index] as char;
+        self.index += 1;
+        Ok(out)
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        if self.index >= self.data.len() {
+            return None;
+        }
+
+        Some(self.data[self.index] as char)
+    }
+
+    pub fn next_n(&mut self, n: usize) -> Result<&'a [u8], Nmea0183Error> {
+        if self.index + n > self.data.len() {
+            return Err(Nmea0183Error::Incomplete);
+        }
+
+        let out = &self.data[self.index..self.index + n];
+        self.index += n;
+        Ok(out)
+    }
+
+    pub fn skip(&mut self, n: usize) {
+        self.index += n;
+    }
+
+    pub fn expect(&mut self, c: char) -> Result<(), Nmea0183Error> {
+        let found = self.next()?;
+        if found != c {
+            return Err(Nmea0183Error::UnexpectedChar(found));
+        }
+
+        Ok(())
+    }
+
+    pub fn expect_bytes(&mut self, bytes: &[u8]) -> Result<(), Nmea0183Error> {
+        let mut i = 0;
+        while i < bytes.len() {
+            let found = self.next()?;
+            if found != bytes[i] as char {
+                return Err(Nmea0183Error::UnexpectedChar(found));
+            }
+
+            i += 1;
+        }
+
+        Ok(())
+    }
+
+    pub fn skip_if(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.index += 1;
+            return true;
+        }
+
+        false
+    }
+
+    pub fn skip_while(&mut self, c: char) {
+        while self.skip_if(c) {}
+    }
+
+    pub fn take_until(&mut self, c: char) -> Result<&'a [u8], Nmea0183Error> {
+        let start = self.index;
+        while self.next()? != c {}
+
+        Ok(&self.data[start..self.index - 1])
+    }
+
+    pub fn take_until_or_end(&mut self, c: char) -> &'a [u8] {
+        let start = self.index;
+        while self.peek() != Some(c) && self.next().is_ok() {}
+
+        &self.data[start..self.index]
+    }
+
+    pub fn remaining_str(&self) -> Result<&'a str, Nmea0183Error> {
+        Ok(core::str::from_utf8(&self.data[self.index..])?)
+    }
+
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.data[self.index..]
+    }
+
+    /// Parses a value, on failure the fields of the value are skipped
+    /// and the error points to them.
+    pub fn parse<T: FromParser<'a>>(&mut self) -> Result<T, Nmea0183Error> {
+        let start = self.index;
+        let result = T::parse(self).and_then(|value| {
+            let Some(separator) = self.take_on_parse else {
+                return Ok(value);
+            };
+
+            // The value has to use all of its fields,
+            // values spanning multiple fields may have already taken the separator after them
+            let taken = self.data[start..self.index]
+                .iter()
+                .filter(|&&x| x == separator as u8)
+                .count();
+            if taken >= T::FIELDS {
+                return Ok(value);
+            }
+
+            match self.peek() {
+                Some(c) if c != separator => Err(Nmea0183Error::UnexpectedChar(c)),
+                _ => {
+                    self.skip_if(separator);
+                    Ok(value)
+                }
+            }
+        });
+
+        match result {
+            Ok(value) => Ok(value),
+            Err(err) => {
+                self.index = start;
+                let end = self.skip_fields(T::FIELDS);
+                match err {
+                    // Already points to the field of a nested value
+                    #[cfg(feature = "alloc")]
+                    Nmea0183Error::InvalidField(_) => Err(err),
+                    err => Err(self.field_error(start, end, T::NAME, err)),
+                }
+            }
+        }
+    }
+
+    /// Parses a value, returning `None` if its first field is empty.
+    /// In lenient mode invalid values are also `None`.
+    pub fn parse_optional<T: FromParser<'a>>(&mut self) -> Result<Option<T>, Nmea0183Error> {
+        match self.peek() {
+            None => Ok(None),
+            Some(c) if Some(c) == self.take_on_parse => {
+                self.skip_fields(T::FIELDS);
+                Ok(None)
+            }
+            Some(_) => match self.parse() {
+                Ok(value) => Ok(Some(value)),
+                Err(_) if self.lenient => Ok(None),
+                Err(err) => Err(err),
+            },
+        }
+    }
+
+    /// Points an error at the field the parser stopped in, skipping the rest of the field.
+    pub fn locate(&mut self, error: Nmea0183Error) -> Nmea0183Error {
+        #[cfg(feature = "alloc")]
+        if let Nmea0183Error::InvalidField(_) = error {
+            return error;
+        }
+
+        // The parser is past the character that caused the error
+        let position = self.index.min(self.data.len()).saturating_sub(1);
+        let separator = self.take_on_parse.unwrap_or(',') as u8;
+        let start = self.data[..position]
+            .iter()
+            .rposition(|&x| x == separator)
+            .map_or(0, |x| x + 1);
+
+        self.index = start;
+        let end = self.skip_fields(1);
+        self.field_error(start, end, "field", error)
+    }
+
+    /// Skips to the end of the given number of fields, returning the end of the last field.
+    fn skip_fields(&mut self, fields: usize) -> usize {
+        let separator = self.take_on_parse.unwrap_or(',');
+        let mut end = self.index;
+        for _ in 0..fields {
+            self.take_until_or_end(separator);
+            end = self.index;
+            self.skip_if(separator);
+        }
+
+        end
+    }
+
+    /// Creates an error for the data from `start` to `end`, recording it in lenient mode.
+    #[cfg(feature = "alloc")]
+    fn field_error(
+        &mut self,
+        start: usize,
+        end: usize,
+        expected: &'static str,
+        error: Nmea0183Error,
+    ) -> Nmea0183Error {
+        let separator = self.take_on_parse.unwrap_or(',') as u8;
+        let error = FieldError {
+            field: self.data[..start]
+                .iter()
+                .filter(|&&x| x == separator)
+                .count()
+                + 1,
+            offset: self.offset + start,
+            length: end - start,
+            expected,
+            raw: String::from_utf8_lossy(&self.data[start..end]).into_owned(),
+            error: Box::new(error),
+        };
+
+        if self.lenient {
+            self.diagnostics.push(error.clone());
+        }
+        Nmea0183Error::InvalidField(Box::new(error))
+    }
+
+    /// Without `alloc` errors can't hold where they are, so they are returned as they are.
+    #[cfg(not(feature = "alloc"))]
+    fn field_error(
+        &mut self,
+        _start: usize,
+        _end: usize,
+        _expected: &'static str,
+        error: Nmea0183Error,
+    ) -> Nmea0183Error {
+        error
+    }
+}
+
+pub trait FromParser<'a>: Sized {
+    /// Name of the expected value, used in errors.
+    const NAME: &'static str;
+    /// Number of fields the value spans.
+    const FIELDS: usize = 1;
+
+    fn parse(parser: &mut Parser<'a>) -> Result<Self, Nmea0183Error>;
+}
+
+impl<'a> FromParser<'a> for u8 {
+    const NAME: &'static str = "integer";
+
+    fn parse(parser: &mut Parser<'a>) -> Result<Self, Nmea0183Error> {
+        let bytes = parser.take_while(|c| c.is_ascii_digit());
+        Ok(str::from_utf8(bytes)?.parse::<u8>()?)
+    }
+}
+
+impl<'a> FromParser<'a> for i8 {
+    const NAME: &'static str = "integer";
+
+    fn parse(parser: &mut Parser<'a>) -> Result<Self, Nmea0183Error> {
+        let bytes = parser.take_while(|c| matches!(c, '-' | '+' | '0'..='9'));
+        Ok(str::from_utf8(bytes)?.parse::<i8>()?)
+    }
+}
+
+impl<'a> FromParser<'a> for u16 {
+    const NAME: &'static str = "integer";
+
+    fn parse(parser: &mut Parser<'a>) -> Result<Self, Nmea0183Error> {
+        let bytes = parser.take_while(|c| c.is_ascii_digit());
+        Ok(str::from_utf8(bytes)?.parse::<u16>()?)
+    }
+}
+
+impl<'a> FromParser<'a> for f32 {
+    const NAME: &'static str = "number";
+
+    fn parse(parser: &mut Parser<'a>) -> Result<Self, Nmea0183Error> {
+        let bytes = parser.take_while(|c| matches!(c, '.' | '-' | '+' | 'e' | 'E' | '0'..='9'));
+        Ok(str::from_utf8(bytes)?.parse::<f32>()?)
+    }
+}
+
+impl<'a> FromParser<'a> for String {
+    const NAME: &'static str = "text";
+
+    fn parse(parser: &mut Parser<'a>) -> Result<Self, Nmea0183Error> {
+        let bytes = parser.take_until_or_end(',');
+
+        // Handle escape codes (^(ascii hex))
+        let mut i = 0;
+        let mut out = String::new();
+
+        while i < bytes.len() {
+            let c = bytes[i];
+            let c = if c == b'^' {
+                let hex = bytes.get(i + 1..i + 3).ok_or(Nmea0183Error::Incomplete)?;
+                i += 3;
+                u8::from_str_radix(str::from_utf8(hex)?, 16)? as char
+            } else {
+                i += 1;
+                c as char
+            };
+            out.write_char(c).map_err(|_| Nmea0183Error::Capacity)?;
+        }
+
+        Ok(out)
+    }
+}
+
+#[macro_export]
+macro_rules! quick_parser {
+    ($for:ty, {
+        $($chr:literal => $variant:ident),*$(,)?
+    }) => {
+        impl<'a> $crate::parser::FromParser<'a> for $for {
+            const NAME: &'static str = stringify!($for);
+
+            fn parse(parser: &mut $crate::parser::Parser<'a>) -> Result<Self, $crate::error::Nmea0183Error> {
+                let chr = parser.next()?;
+                Ok(match chr {
+                    $($chr => Self::$variant),*,
+                    _ => return Err($crate::error::Nmea0183Error::UnexpectedChar(chr)),
+                })
+            }
+        }
+
+        #[allow(dead_code)]
+        impl $for {
+            /// Gets the character this value is encoded as.
+            pub fn to_char(self) -> char {
+                match self {
+                    $(Self::$variant => $chr),*
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(data: &str) -> Result<String, Nmea0183Error> {
+        Parser::new(data.as_bytes()).parse::<String>()
+    }
+
+    #[test]
+    fn unescapes_text() {
+        assert_eq!(text("A^2CB").unwrap().as_str(), "A,B");
+    }
+
+    #[test]
+    fn rejects_incomplete_escapes() {
+        assert!(text("ANTSTATUS=OK^").is_err());
+        assert!(text("ANTSTATUS=OK^2").is_err());
+        assert!(crate::Message::parse(b"$GPTXT,01,01,02,ANTSTATUS=OK^2*57").is_err());
+    }
+}
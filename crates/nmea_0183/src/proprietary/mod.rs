@@ -0,0 +1,171 @@
+//! Proprietary sentences, `$P` followed by a three character manufacturer ID.
+//! The rest of the sentence is defined by the manufacturer.
+
+use core::str;
+
+use self::mtk::MtkSentence;
+
+use super::{error::Nmea0183Error, parser::Parser, text, String};
+
+pub mod mtk;
+
+/// Parses the data of a proprietary sentence, after the manufacturer ID.
+pub type Decoder = fn(&mut Parser) -> Result<Proprietary, Nmea0183Error>;
+
+/// Decoders of the supported manufacturers.
+pub const BUILTIN_DECODERS: &[([u8; 3], Decoder)] = &[(*b"MTK", |parser| {
+    Ok(Proprietary::Mtk(MtkSentence::parse(parser)?))
+})];
+
+/// Decoders of proprietary sentences by manufacturer ID, built by the caller to add or replace decoders.
+/// Sentences from manufacturers without a decoder are kept as [`Proprietary::Unknown`].
+#[derive(Clone, Copy)]
+pub struct Decoders<'a> {
+    decoders: &'a [([u8; 3], Decoder)],
+    fallback: Option<&'a Decoders<'a>>,
+}
+
+impl Decoders<'static> {
+    /// The decoders of the supported manufacturers.
+    pub const BUILTIN: Self = Self::new(BUILTIN_DECODERS);
+}
+
+impl<'a> Decoders<'a> {
+    /// Only the given decoders, the first one for a manufacturer is used.
+    pub const fn new(decoders: &'a [([u8; 3], Decoder)]) -> Self {
+        Self {
+            decoders,
+            fallback: None,
+        }
+    }
+
+    /// Uses other decoders for manufacturers without one here, such as [`Decoders::BUILTIN`].
+    pub const fn with_fallback(self, fallback: &'a Decoders<'a>) -> Self {
+        Self {
+            fallback: Some(fallback),
+            ..self
+        }
+    }
+
+    /// Gets the decoder for a manufacturer.
+    pub fn get(&self, manufacturer: [u8; 3]) -> Option<Decoder> {
+        self.decoders
+            .iter()
+            .find(|(id, _)| *id == manufacturer)
+            .map(|(_, decoder)| *decoder)
+            .or_else(|| self.fallback.and_then(|x| x.get(manufacturer)))
+    }
+}
+
+impl Default for Decoders<'static> {
+    fn default() -> Self {
+        Self::BUILTIN
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Proprietary {
+    /// MediaTek.
+    Mtk(MtkSentence),
+    /// A sentence from a manufacturer without a decoder.
+    Unknown { manufacturer: [u8; 3], data: String },
+}
+
+impl Proprietary {
+    pub fn parse(manufacturer: [u8; 3], parser: &mut Parser) -> Result<Proprietary, Nmea0183Error> {
+        Self::parse_with(manufacturer, parser, &Decoders::BUILTIN)
+    }
+
+    /// Parses the data with the decoder registered for the manufacturer.
+    pub fn parse_with(
+        manufacturer: [u8; 3],
+        parser: &mut Parser,
+        decoders: &Decoders,
+    ) -> Result<Proprietary, Nmea0183Error> {
+        match decoders.get(manufacturer) {
+            Some(decoder) => decoder(parser),
+            None => Ok(Proprietary::Unknown {
+                manufacturer,
+                data: text(str::from_utf8(parser.take_while(|_| true))?)?,
+            }),
+        }
+    }
+
+    pub fn manufacturer(&self) -> [u8; 3] {
+        match self {
+            Proprietary::Mtk(_) => *b"MTK",
+            Proprietary::Unknown { manufacturer, .. } => *manufacturer,
+        }
+    }
+}
+
+/// Gets a human readable name for a manufacturer ID.
+/// Returns `None` for unknown manufacturers.
+pub fn manufacturer_name(id: [u8; 3]) -> Option<&'static str> {
+    Some(match &id {
+        b"MTK" => "MediaTek",
+        b"UBX" => "u-blox",
+        b"GRM" => "Garmin",
+        b"SRF" => "SiRF",
+        b"STM" => "STMicroelectronics",
+        b"ASH" => "Ashtech",
+        b"TNL" => "Trimble",
+        b"RWI" => "Rockwell",
+        b"FEC" => "Furuno",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Message, Sentence};
+
+    use super::*;
+
+    const GARMIN: &[([u8; 3], Decoder)] = &[(*b"GRM", garmin)];
+
+    fn garmin(parser: &mut Parser) -> Result<Proprietary, Nmea0183Error> {
+        parser.take_while(|_| true);
+        Ok(Proprietary::Unknown {
+            manufacturer: *b"GRM",
+            data: text("decoded")?,
+        })
+    }
+
+    fn proprietary(line: &[u8], decoders: &Decoders) -> Proprietary {
+        match Message::parse_with(line, decoders).unwrap().message {
+            Sentence::Proprietary(sentence) => sentence,
+            sentence => panic!("Expected a proprietary sentence, got {sentence:?}"),
+        }
+    }
+
+    #[test]
+    fn uses_registered_decoders() {
+        let line = b"$PGRME,15.0,M,45.0,M,25.0,M*1C";
+        let decoders = Decoders::new(GARMIN).with_fallback(&Decoders::BUILTIN);
+
+        let Proprietary::Unknown { data, .. } = proprietary(line, &decoders) else {
+            panic!("Expected the registered decoder to be used");
+        };
+        assert_eq!(data.as_str(), "decoded");
+
+        let Proprietary::Unknown { data, .. } = proprietary(line, &Decoders::BUILTIN) else {
+            panic!("Expected no decoder for GRM");
+        };
+        assert_eq!(data.as_str(), "E,15.0,M,45.0,M,25.0,M");
+    }
+
+    #[test]
+    fn falls_back_to_other_decoders() {
+        let line = b"$PMTK001,220,3*30";
+        let decoders = Decoders::new(GARMIN).with_fallback(&Decoders::BUILTIN);
+        assert!(matches!(proprietary(line, &decoders), Proprietary::Mtk(_)));
+
+        let decoders = Decoders::new(GARMIN);
+        assert!(matches!(
+            proprietary(line, &decoders),
+            Proprietary::Unknown { .. }
+        ));
+    }
+}
@@ -0,0 +1,312 @@
+//! MediaTek sentences (`$PMTK`), a three digit packet type followed by its fields.
+//! Commands sent to the receiver are acknowledged with a `PMTK001` sentence,
+//! and queries are answered with a `PMTK5xx` or `PMTK7xx` sentence.
+
+#[cfg(feature = "alloc")]
+use alloc::format;
+
+#[cfg(feature = "alloc")]
+use crate::writer::Writer;
+use crate::{error::Nmea0183Error, parser::Parser, quick_parser, List, String, TryPush};
+
+/// Sentences in the order of the output rate fields (`PMTK314` and `PMTK514`).
+/// Empty names are reserved fields.
+pub const OUTPUT_SENTENCES: [&str; 19] = [
+    "GLL", "RMC", "VTG", "GGA", "GSA", "GSV", "GRS", "GST", "", "", "", "", "", "MALM", "MEPH",
+    "MDGP", "MDBG", "ZDA", "MCHN",
+];
+
+/// Most fields kept of packet types without a decoder.
+pub const MAX_FIELDS: usize = 16;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MtkSentence {
+    /// `PMTK001`, acknowledges a command.
+    Acknowledge(Acknowledge),
+    /// `PMTK010`, sent on startup and when the receiver changes state.
+    SystemMessage(SystemMessage),
+    /// `PMTK011`, text sent on startup.
+    Text(String),
+    /// `PMTK500`, the time between fixes in milliseconds.
+    FixInterval(u16),
+    /// `PMTK513`, whether SBAS is enabled.
+    SbasEnabled(bool),
+    /// `PMTK514`, the output rate of each sentence.
+    OutputRates(OutputRates),
+    /// `PMTK705`, the firmware release.
+    Release(Release),
+    /// A packet type without a decoder.
+    Other {
+        packet_type: u16,
+        fields: List<String, MAX_FIELDS>,
+    },
+}
+
+/// Commands that can be sent to the receiver.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MtkCommand {
+    /// `PMTK101`, restarts using all available data.
+    HotRestart,
+    /// `PMTK102`, restarts without the ephemeris data.
+    WarmRestart,
+    /// `PMTK103`, restarts without the time, position, almanac and ephemeris data.
+    ColdRestart,
+    /// `PMTK104`, cold restart that also resets the configuration to the factory defaults.
+    FullColdRestart,
+    /// `PMTK220`, sets the time between fixes in milliseconds, 100 to 10000.
+    SetFixInterval(u16),
+    /// `PMTK251`, sets the baud rate, 0 is the default baud rate.
+    SetBaudRate(u32),
+    /// `PMTK313`, enables or disables searching for SBAS satellites.
+    SetSbasEnabled(bool),
+    /// `PMTK314`, sets the output rate of each sentence.
+    SetOutputRates(OutputRates),
+    /// `PMTK314,-1`, restores the default output rates.
+    ResetOutputRates,
+    /// `PMTK400`, answered with `PMTK500`.
+    QueryFixInterval,
+    /// `PMTK413`, answered with `PMTK513`.
+    QuerySbasEnabled,
+    /// `PMTK414`, answered with `PMTK514`.
+    QueryOutputRates,
+    /// `PMTK605`, answered with `PMTK705`.
+    QueryRelease,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Acknowledge {
+    /// Packet type of the acknowledged command.
+    pub command: u16,
+    pub flag: AcknowledgeFlag,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AcknowledgeFlag {
+    InvalidCommand,
+    UnsupportedCommand,
+    Failed,
+    Succeeded,
+}
+
+quick_parser!(AcknowledgeFlag, {
+    '0' => InvalidCommand,
+    '1' => UnsupportedCommand,
+    '2' => Failed,
+    '3' => Succeeded,
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SystemMessage {
+    Startup,
+    /// The receiver asks for extended prediction orbit (EPO) data.
+    EpoRequest,
+    /// The receiver switched to normal mode.
+    Normal,
+    Unknown(u16),
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutputRates {
+    /// Output rate of each sentence in [`OUTPUT_SENTENCES`], as a multiple of the fix interval.
+    /// Disabled sentences are 0.
+    pub rates: List<u8, { OUTPUT_SENTENCES.len() }>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Release {
+    pub release: String,
+    pub build_id: String,
+    pub model: String,
+    pub sdk_version: Option<String>,
+}
+
+impl MtkSentence {
+    pub fn parse(parser: &mut Parser) -> Result<MtkSentence, Nmea0183Error> {
+        let packet_type = parser.parse::<u16>()?;
+
+        let sentence = match packet_type {
+            1 => {
+                let command = parser.parse::<u16>()?;
+                let flag = parser.parse::<AcknowledgeFlag>()?;
+                MtkSentence::Acknowledge(Acknowledge { command, flag })
+            }
+            10 => MtkSentence::SystemMessage(match parser.parse::<u16>()? {
+                1 => SystemMessage::Startup,
+                2 => SystemMessage::EpoRequest,
+                3 => SystemMessage::Normal,
+                message => SystemMessage::Unknown(message),
+            }),
+            11 => MtkSentence::Text(parser.parse::<String>()?),
+            500 => {
+                let interval = parser.parse::<u16>()?;
+                // The remaining fields are only used for AlwaysLocate mode
+                parser.take_while(|_| true);
+                MtkSentence::FixInterval(interval)
+            }
+            513 => MtkSentence::SbasEnabled(parser.parse::<u8>()? != 0),
+            514 => {
+                let mut rates = List::new();
+                while parser.peek().is_some() {
+                    // Rates of sentences newer firmware added can't be looked up
+                    let _ = rates.try_push(parser.parse::<u8>()?);
+                }
+                MtkSentence::OutputRates(OutputRates { rates })
+            }
+            705 => {
+                let release = parser.parse::<String>()?;
+                let build_id = parser.parse::<String>()?;
+                let model = parser.parse::<String>()?;
+                let sdk_version = parser.parse_optional::<String>()?;
+                MtkSentence::Release(Release {
+                    release,
+                    build_id,
+                    model,
+                    sdk_version,
+                })
+            }
+            _ => {
+                let mut fields = List::new();
+                while parser.peek().is_some() {
+                    fields.try_push(parser.parse::<String>()?)?;
+                }
+                MtkSentence::Other {
+                    packet_type,
+                    fields,
+                }
+            }
+        };
+        parser.assert_empty()?;
+
+        Ok(sentence)
+    }
+}
+
+impl MtkSentence {
+    pub fn packet_type(&self) -> u16 {
+        match self {
+            MtkSentence::Acknowledge(_) => 1,
+            MtkSentence::SystemMessage(_) => 10,
+            MtkSentence::Text(_) => 11,
+            MtkSentence::FixInterval(_) => 500,
+            MtkSentence::SbasEnabled(_) => 513,
+            MtkSentence::OutputRates(_) => 514,
+            MtkSentence::Release(_) => 705,
+            MtkSentence::Other { packet_type, .. } => *packet_type,
+        }
+    }
+}
+
+impl MtkCommand {
+    pub fn packet_type(&self) -> u16 {
+        match self {
+            MtkCommand::HotRestart => 101,
+            MtkCommand::WarmRestart => 102,
+            MtkCommand::ColdRestart => 103,
+            MtkCommand::FullColdRestart => 104,
+            MtkCommand::SetFixInterval(_) => 220,
+            MtkCommand::SetBaudRate(_) => 251,
+            MtkCommand::SetSbasEnabled(_) => 313,
+            MtkCommand::SetOutputRates(_) | MtkCommand::ResetOutputRates => 314,
+            MtkCommand::QueryFixInterval => 400,
+            MtkCommand::QuerySbasEnabled => 413,
+            MtkCommand::QueryOutputRates => 414,
+            MtkCommand::QueryRelease => 605,
+        }
+    }
+
+    /// Gets the packet type of the sentence answering this command.
+    /// Returns `None` for baud rate changes, which are not answered at the old baud rate.
+    pub fn response_type(&self) -> Option<u16> {
+        Some(match self {
+            MtkCommand::HotRestart
+            | MtkCommand::WarmRestart
+            | MtkCommand::ColdRestart
+            | MtkCommand::FullColdRestart => 10,
+            MtkCommand::SetBaudRate(_) => return None,
+            MtkCommand::QueryFixInterval => 500,
+            MtkCommand::QuerySbasEnabled => 513,
+            MtkCommand::QueryOutputRates => 514,
+            MtkCommand::QueryRelease => 705,
+            _ => 1,
+        })
+    }
+
+    /// Checks if a sentence answers this command.
+    pub fn is_response(&self, sentence: &MtkSentence) -> bool {
+        match (self.response_type(), sentence) {
+            (Some(1), MtkSentence::Acknowledge(ack)) => ack.command == self.packet_type(),
+            (Some(response), sentence) => response == sentence.packet_type(),
+            (None, _) => false,
+        }
+    }
+
+    /// Encodes the command as a sentence, including the line ending.
+    #[cfg(feature = "alloc")]
+    pub fn encode(&self) -> String {
+        let mut writer = Writer::new();
+        match self {
+            MtkCommand::SetFixInterval(interval) => {
+                writer.field(interval);
+            }
+            MtkCommand::SetBaudRate(baud_rate) => {
+                writer.field(baud_rate);
+            }
+            MtkCommand::SetSbasEnabled(enabled) => {
+                writer.field(u8::from(*enabled));
+            }
+            MtkCommand::SetOutputRates(rates) => {
+                for rate in rates.rates.iter() {
+                    writer.field(rate);
+                }
+            }
+            MtkCommand::ResetOutputRates => {
+                writer.field(-1);
+            }
+            _ => {}
+        }
+
+        writer.finish(&format!("PMTK{:03}", self.packet_type()))
+    }
+}
+
+impl OutputRates {
+    /// Creates output rates from sentence names and rates, other sentences are disabled.
+    /// Returns the name of the first unknown sentence as an error.
+    pub fn from_rates<'a>(
+        rates: impl IntoIterator<Item = (&'a str, u8)>,
+    ) -> Result<OutputRates, &'a str> {
+        let mut out: List<u8, { OUTPUT_SENTENCES.len() }> =
+            OUTPUT_SENTENCES.iter().map(|_| 0).collect();
+        for (sentence, rate) in rates {
+            let index = OUTPUT_SENTENCES
+                .iter()
+                .position(|x| !x.is_empty() && x.eq_ignore_ascii_case(sentence))
+                .ok_or(sentence)?;
+            out[index] = rate;
+        }
+
+        Ok(OutputRates { rates: out })
+    }
+
+    /// Gets the output rate of a sentence, `None` if the receiver did not report it.
+    pub fn rate(&self, sentence: &str) -> Option<u8> {
+        let index = OUTPUT_SENTENCES.iter().position(|x| *x == sentence)?;
+        self.rates.get(index).copied()
+    }
+
+    /// Iterates over the enabled sentences and their output rates.
+    pub fn enabled(&self) -> impl Iterator<Item = (&'static str, u8)> + '_ {
+        OUTPUT_SENTENCES
+            .iter()
+            .zip(self.rates.iter())
+            .filter(|(name, rate)| !name.is_empty() && **rate > 0)
+            .map(|(name, rate)| (*name, *rate))
+    }
+}
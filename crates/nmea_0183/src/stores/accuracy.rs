@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+use crate::{
+    misc::delayed::Delayed,
+    packets::{
+        pseudorange_error_statistics::PseudorangeErrorStatistics,
+        satellite_fault_detection::SatelliteFaultDetection,
+    },
+    Sentence,
+};
+
+/// Accuracy data older than this is not used.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Position accuracy and integrity, from GST and GBS sentences.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Accuracy {
+    pub errors: Delayed<PseudorangeErrorStatistics>,
+    pub faults: Delayed<SatelliteFaultDetection>,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Integrity {
+    /// No fault detection data is being received.
+    Unknown,
+    /// No satellite fault was detected.
+    Ok,
+    /// A satellite was detected as faulty.
+    Fault(u8),
+}
+
+impl Accuracy {
+    pub fn new() -> Self {
+        Self {
+            errors: Delayed::new().with_timeout(TIMEOUT),
+            faults: Delayed::new().with_timeout(TIMEOUT),
+        }
+    }
+
+    /// Gets the error ellipse as (semi-major, semi-minor, orientation), one standard deviation in meters.
+    pub fn error_ellipse(&self) -> Option<(f32, f32, f32)> {
+        let errors = self.errors.get().ok()?;
+        Some((errors.semi_major?, errors.semi_minor?, errors.orientation?))
+    }
+
+    pub fn integrity(&self) -> Integrity {
+        match self.faults.get().ok() {
+            None => Integrity::Unknown,
+            Some(faults) => match faults.failed_satellite {
+                Some(satellite) => Integrity::Fault(satellite),
+                None => Integrity::Ok,
+            },
+        }
+    }
+
+    pub fn handle(&mut self, sentence: &Sentence) {
+        match sentence {
+            Sentence::Gst(sentence) => self.errors.update(sentence.clone()),
+            Sentence::Gbs(sentence) => self.faults.update(sentence.clone()),
+            _ => {}
+        }
+    }
+}
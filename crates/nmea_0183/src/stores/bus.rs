@@ -0,0 +1,126 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::Nmea0183Error,
+    view::{self, Address},
+    Message,
+};
+
+/// The message rate is averaged over this window.
+pub const RATE_WINDOW: Duration = Duration::from_secs(10);
+
+/// Streams that haven't sent a sentence for this long are shown as stale.
+pub const STALE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Statistics of every line received, to find flaky wiring and misconfigured instruments.
+pub struct Bus {
+    /// Streams keyed by talker and sentence type, or the manufacturer of proprietary sentences.
+    pub streams: BTreeMap<Address, Stream>,
+    /// Lines too damaged to tell their talker and sentence type.
+    pub unreadable: u32,
+}
+
+/// The sentences of one type from one talker.
+#[derive(Default)]
+pub struct Stream {
+    /// Number of lines received, including invalid ones.
+    pub received: u32,
+    /// When the last line was received.
+    pub last_seen: Option<Instant>,
+    /// Lines with a checksum that didn't match.
+    pub checksum_failures: u32,
+    /// Lines of a sentence type that isn't supported.
+    pub unknown_types: u32,
+    /// Lines that failed to parse for another reason.
+    pub parse_errors: u32,
+    /// The last parse error.
+    pub last_error: Option<Nmea0183Error>,
+    /// When the lines in the last [`RATE_WINDOW`] were received.
+    history: VecDeque<Instant>,
+}
+
+impl Bus {
+    pub fn new() -> Self {
+        Self {
+            streams: BTreeMap::new(),
+            unreadable: 0,
+        }
+    }
+
+    /// Records a received line and the result of parsing it.
+    pub fn handle(&mut self, line: &[u8], result: &Result<Message, Nmea0183Error>) {
+        let Some(address) = view::address(line) else {
+            self.unreadable += 1;
+            return;
+        };
+
+        let now = Instant::now();
+        let stream = self.streams.entry(address).or_default();
+        stream.received += 1;
+        stream.last_seen = Some(now);
+        stream.history.push_back(now);
+        while stream
+            .history
+            .front()
+            .is_some_and(|x| now.duration_since(*x) > RATE_WINDOW)
+        {
+            stream.history.pop_front();
+        }
+
+        match result {
+            Ok(_) => {}
+            Err(Nmea0183Error::InvalidChecksum) => stream.checksum_failures += 1,
+            // Lines starting with `!` are encapsulated sentences, such as AIS, which aren't supported
+            Err(Nmea0183Error::UnknownType(_) | Nmea0183Error::MissingPrefix) => {
+                stream.unknown_types += 1
+            }
+            Err(err) => {
+                stream.parse_errors += 1;
+                stream.last_error = Some(err.clone());
+            }
+        }
+    }
+
+    /// Total number of invalid lines, of all streams.
+    pub fn errors(&self) -> u32 {
+        self.unreadable
+            + self
+                .streams
+                .values()
+                .map(|x| x.checksum_failures + x.parse_errors)
+                .sum::<u32>()
+    }
+}
+
+impl Stream {
+    /// Lines received per second, over the last [`RATE_WINDOW`].
+    pub fn rate(&self) -> f32 {
+        let now = Instant::now();
+        let recent = self
+            .history
+            .iter()
+            .filter(|x| now.duration_since(**x) <= RATE_WINDOW)
+            .count();
+        recent as f32 / RATE_WINDOW.as_secs_f32()
+    }
+
+    /// Time since the last line was received.
+    pub fn age(&self) -> Option<Duration> {
+        self.last_seen.map(|x| x.elapsed())
+    }
+
+    pub fn is_stale(&self) -> bool {
+        match self.age() {
+            Some(age) => age > STALE_TIMEOUT,
+            None => true,
+        }
+    }
+
+    /// Number of lines that were invalid.
+    pub fn errors(&self) -> u32 {
+        self.checksum_failures + self.parse_errors
+    }
+}
@@ -0,0 +1,115 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    misc::delayed::{Delayed, DelayedResult},
+    stores::HISTORY_SAMPLES,
+    Sentence,
+};
+
+/// Depth data older than this is not used.
+const TIMEOUT: Duration = Duration::from_secs(5);
+/// Minimum time between history samples, as sounders often send multiple depth sentences.
+const HISTORY_INTERVAL: Duration = Duration::from_secs(1);
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Depth {
+    /// Depth below the transducer in meters.
+    pub below_transducer: Delayed<f32>,
+    /// Depth below the surface in meters, from DBS.
+    pub below_surface: Delayed<f32>,
+    /// Transducer offset in meters, overrides the offset sent by the sounder.
+    /// Positive is the distance to the waterline, negative is the distance to the keel.
+    pub offset: Option<f32>,
+    /// Transducer offset sent in the last DPT sentence.
+    pub sounder_offset: Option<f32>,
+    /// Depth in meters below which the shallow water alarm is raised.
+    pub shallow_threshold: Option<f32>,
+    /// Depth with the offset applied.
+    pub history: VecDeque<f32>,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_history: Option<Instant>,
+}
+
+impl Depth {
+    pub fn new() -> Self {
+        Self {
+            below_transducer: Delayed::new().with_timeout(TIMEOUT),
+            below_surface: Delayed::new().with_timeout(TIMEOUT),
+            offset: None,
+            sounder_offset: None,
+            shallow_threshold: None,
+            history: VecDeque::new(),
+            last_history: None,
+        }
+    }
+
+    /// The transducer offset in use.
+    pub fn offset(&self) -> f32 {
+        self.offset.or(self.sounder_offset).unwrap_or(0.0)
+    }
+
+    /// Gets the depth with the transducer offset applied.
+    /// Falls back to the depth below surface if there is no depth below transducer.
+    pub fn depth(&self) -> Option<f32> {
+        if let DelayedResult::Ok(depth) = self.below_transducer.get() {
+            return Some(depth + self.offset());
+        }
+
+        self.below_surface.get().ok().copied()
+    }
+
+    /// If the depth is below the shallow water threshold.
+    pub fn is_shallow(&self) -> bool {
+        match (self.depth(), self.shallow_threshold) {
+            (Some(depth), Some(threshold)) => depth < threshold,
+            _ => false,
+        }
+    }
+
+    pub fn handle(&mut self, sentence: &Sentence) {
+        match sentence {
+            Sentence::Dbt(sentence) => match sentence.depth() {
+                Some(depth) => self.below_transducer.update(depth),
+                None => return,
+            },
+            Sentence::Dbs(sentence) => match sentence.depth() {
+                Some(depth) => self.below_surface.update(depth),
+                None => return,
+            },
+            Sentence::Dpt(sentence) => {
+                self.sounder_offset = sentence.offset;
+                match sentence.depth {
+                    Some(depth) => self.below_transducer.update(depth),
+                    None => return,
+                }
+            }
+            _ => return,
+        }
+
+        self.update_history();
+    }
+
+    fn update_history(&mut self) {
+        let now = Instant::now();
+        if self
+            .last_history
+            .is_some_and(|x| now - x < HISTORY_INTERVAL)
+        {
+            return;
+        }
+
+        let Some(depth) = self.depth() else {
+            return;
+        };
+
+        self.last_history = Some(now);
+        self.history.push_back(depth);
+        while self.history.len() > HISTORY_SAMPLES {
+            self.history.pop_front();
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+use crate::{
+    faa_mode::FaaMode,
+    misc::delayed::Delayed,
+    packets::{
+        geographic_position::Status, heading_deviation_variation::HeadingDeviationVariation,
+    },
+    Sentence,
+};
+
+/// Heading data older than this is not used.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Heading {
+    /// Heading from a true heading sensor (gyro or satellite compass).
+    pub true_heading: Delayed<f32>,
+    /// Heading from a magnetic compass, corrected for deviation.
+    pub magnetic_heading: Delayed<f32>,
+    /// Deviation of the magnetic compass.
+    pub deviation: Option<f32>,
+    /// Magnetic variation at the current position.
+    pub variation: Option<f32>,
+    /// Rate of turn in degrees per minute, negative is to port.
+    pub rate_of_turn: Delayed<f32>,
+    /// Mode of the last THS sentence.
+    pub mode: Option<FaaMode>,
+}
+
+impl Heading {
+    pub fn new() -> Self {
+        Self {
+            true_heading: Delayed::new().with_timeout(TIMEOUT),
+            magnetic_heading: Delayed::new().with_timeout(TIMEOUT),
+            deviation: None,
+            variation: None,
+            rate_of_turn: Delayed::new().with_timeout(TIMEOUT),
+            mode: None,
+        }
+    }
+
+    /// Gets the true heading, falling back to the magnetic heading corrected for variation.
+    pub fn heading(&self) -> Option<f32> {
+        if let Some(heading) = self.true_heading.get().ok() {
+            return Some(*heading);
+        }
+
+        let magnetic = self.magnetic_heading.get().ok()?;
+        let variation = self.variation?;
+        Some((magnetic + variation).rem_euclid(360.0))
+    }
+
+    pub fn handle(&mut self, sentence: &Sentence) {
+        match sentence {
+            Sentence::Hdt(sentence) => {
+                if let Some(heading) = sentence.heading {
+                    self.true_heading.update(heading);
+                }
+            }
+            Sentence::Hdm(sentence) => {
+                if let Some(heading) = sentence.heading {
+                    self.magnetic_heading.update(heading);
+                }
+            }
+            Sentence::Hdg(sentence) => self.handle_deviation_variation(sentence),
+            Sentence::Ths(sentence) => {
+                self.mode = Some(sentence.mode);
+                match (sentence.heading, sentence.mode) {
+                    (_, FaaMode::DataNotValid) => self.true_heading.invalidate(),
+                    (Some(heading), _) => self.true_heading.update(heading),
+                    _ => {}
+                }
+            }
+            Sentence::Rot(sentence) => match (sentence.rate, sentence.status) {
+                (Some(rate), Status::DataValid) => self.rate_of_turn.update(rate),
+                _ => self.rate_of_turn.invalidate(),
+            },
+            Sentence::Rmc(sentence) => {
+                if let Some(variation) = sentence.variation {
+                    self.variation = Some(variation.degree);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_deviation_variation(&mut self, sentence: &HeadingDeviationVariation) {
+        if let Some(heading) = sentence.magnetic_heading() {
+            self.magnetic_heading.update(heading);
+        }
+
+        self.deviation = sentence.deviation.map(|x| x.degree);
+        if let Some(variation) = sentence.variation {
+            self.variation = Some(variation.degree);
+        }
+    }
+}
@@ -0,0 +1,341 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Utc};
+
+use crate::{
+    coordinate::Coordinate,
+    faa_mode::FaaMode,
+    packets::{
+        active_satellites::{ActiveSatellites, Fix},
+        geographic_position::{GeographicPosition, Status},
+        gnss_fix_data::{Constellation, GnssFixData},
+        ground_speed::GroundSpeed,
+        recommended_minimum::RecommendedMinimum,
+        time_date::TimeDate,
+    },
+    time::{Date, Time},
+    ubx::{
+        packets::position_velocity_time::{FixType, PositionVelocityTime},
+        Packet,
+    },
+    Sentence,
+};
+
+/// Longest time in minutes between two sentences for a time without a date to be taken as the next day.
+const MAX_ROLLOVER_STEP: i64 = 10;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Location {
+    pub latitude: Coordinate,
+    pub longitude: Coordinate,
+    pub time: Time,
+    pub status: Status,
+    pub fix: Fix,
+    pub pdop: f32,
+    pub hdop: f32,
+    pub vdop: f32,
+    /// Course over ground in degrees, true.
+    pub course: Option<f32>,
+    /// Speed over ground in knots.
+    pub speed: Option<f32>,
+    /// Date from the last RMC or ZDA sentence.
+    pub date: Option<Date>,
+    /// The last received time combined with the last received date.
+    pub date_time: Option<DateTime<Utc>>,
+    /// Local time zone from the last ZDA sentence.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_offset"))]
+    pub local_offset: Option<FixedOffset>,
+    /// System clock minus GPS time, measured when the last date time was received.
+    /// Always against the system clock, even for sentences with a TAG block time.
+    /// Includes the delay of the serial connection.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_seconds"))]
+    pub clock_offset: Option<Duration>,
+    /// Fix mode of each constellation, from the last GNS sentence.
+    pub constellation_modes: Vec<(Constellation, FaaMode)>,
+    /// Number of satellites used in the fix.
+    pub satellites_used: Option<u8>,
+    /// Altitude above mean sea level in meters.
+    pub altitude: Option<f32>,
+
+    /// When the sentence being handled was received, by the system clock.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    received: DateTime<Utc>,
+    /// The day after the last date, once a time without a date passed midnight.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    guessed_date: Option<Date>,
+}
+
+impl Location {
+    pub fn new() -> Self {
+        Self {
+            latitude: Coordinate::new(),
+            longitude: Coordinate::new(),
+            time: Time::new(),
+            status: Status::DataInvalid,
+            fix: Fix::NoFix,
+            pdop: 0.0,
+            hdop: 0.0,
+            vdop: 0.0,
+            course: None,
+            speed: None,
+            date: None,
+            date_time: None,
+            local_offset: None,
+            clock_offset: None,
+            constellation_modes: Vec::new(),
+            satellites_used: None,
+            altitude: None,
+            received: Utc::now(),
+            guessed_date: None,
+        }
+    }
+
+    /// Handles a sentence, received at the given system time.
+    pub fn handle(&mut self, sentence: &Sentence, received: DateTime<Utc>) {
+        self.received = received;
+        match sentence {
+            Sentence::Gll(sentence) => self.handle_pos_inner(sentence),
+            Sentence::Gsa(sentence) => self.handel_active_satellites(sentence),
+            Sentence::Vtg(sentence) => self.handle_ground_speed(sentence),
+            Sentence::Rmc(sentence) => self.handle_recommended_minimum(sentence),
+            Sentence::Zda(sentence) => self.handle_time_date(sentence),
+            Sentence::Gns(sentence) => self.handle_gnss_fix_data(sentence),
+            _ => {}
+        }
+    }
+
+    pub fn handle_ubx(&mut self, packet: &Packet) {
+        self.received = Utc::now();
+        match packet {
+            Packet::NavPvt(packet) => self.handle_position_velocity_time(packet),
+            Packet::NavDop(packet) => {
+                self.pdop = packet.pdop;
+                self.hdop = packet.hdop;
+                self.vdop = packet.vdop;
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_pos_inner(&mut self, sentence: &GeographicPosition) {
+        if let (Some(latitude), Some(longitude)) = (sentence.latitude, sentence.longitude) {
+            self.latitude = latitude;
+            self.longitude = longitude;
+        }
+        self.status = sentence.status;
+        if let Some(time) = sentence.time {
+            self.update_time(time, false);
+        }
+    }
+
+    fn handel_active_satellites(&mut self, sentence: &ActiveSatellites) {
+        self.fix = sentence.fix;
+        self.pdop = sentence.pdop;
+        self.hdop = sentence.hdop;
+        self.vdop = sentence.vdop;
+    }
+
+    fn handle_ground_speed(&mut self, sentence: &GroundSpeed) {
+        self.course = sentence.course_true;
+        self.speed = sentence.speed_knots;
+    }
+
+    fn handle_recommended_minimum(&mut self, sentence: &RecommendedMinimum) {
+        self.status = sentence.status;
+        if let Status::DataValid = sentence.status {
+            if let (Some(latitude), Some(longitude)) = (sentence.latitude, sentence.longitude) {
+                self.latitude = latitude;
+                self.longitude = longitude;
+            }
+            self.course = sentence.course_true;
+            self.speed = sentence.speed_knots;
+        }
+
+        self.update_date(sentence.date);
+        if let Some(time) = sentence.time {
+            self.update_time(time, sentence.date.is_some());
+        }
+    }
+
+    fn handle_time_date(&mut self, sentence: &TimeDate) {
+        self.update_date(sentence.date);
+        if let Some(offset) = sentence.local_offset() {
+            self.local_offset = Some(offset);
+        }
+        if let Some(time) = sentence.time {
+            self.update_time(time, sentence.date.is_some());
+        }
+    }
+
+    fn handle_gnss_fix_data(&mut self, sentence: &GnssFixData) {
+        self.constellation_modes = sentence.constellation_modes().collect();
+        self.satellites_used = sentence.satellites;
+        if sentence.is_valid() {
+            if let (Some(latitude), Some(longitude)) = (sentence.latitude, sentence.longitude) {
+                self.latitude = latitude;
+                self.longitude = longitude;
+            }
+            self.altitude = sentence.altitude;
+            if let Some(hdop) = sentence.hdop {
+                self.hdop = hdop;
+            }
+        }
+
+        if let Some(time) = sentence.time {
+            self.update_time(time, false);
+        }
+    }
+
+    fn handle_position_velocity_time(&mut self, packet: &PositionVelocityTime) {
+        self.fix = match packet.fix {
+            FixType::Fix2D => Fix::Fix2D,
+            FixType::Fix3D | FixType::GnssDeadReckoning => Fix::Fix3D,
+            FixType::NoFix | FixType::DeadReckoning | FixType::TimeOnly => Fix::NoFix,
+        };
+        self.status = if packet.fix_ok {
+            Status::DataValid
+        } else {
+            Status::DataInvalid
+        };
+        self.satellites_used = Some(packet.satellites);
+        self.pdop = packet.pdop;
+
+        if packet.fix_ok {
+            self.latitude = packet.latitude;
+            self.longitude = packet.longitude;
+            self.altitude = Some(packet.altitude);
+            self.course = Some(packet.heading);
+            self.speed = Some(packet.speed_knots());
+        }
+
+        self.update_date(packet.date);
+        if let Some(time) = packet.time {
+            self.update_time(time, packet.date.is_some());
+        }
+    }
+
+    fn update_date(&mut self, date: Option<Date>) {
+        if date.is_some() {
+            self.date = date;
+            self.guessed_date = None;
+        }
+    }
+
+    /// Combines the time with the last date, `with_date` is set if the date came in the same sentence.
+    fn update_time(&mut self, time: Time, with_date: bool) {
+        self.time = time;
+
+        let Some(mut date_time) = self
+            .guessed_date
+            .or(self.date)
+            .and_then(|x| x.with_time(&time))
+        else {
+            return;
+        };
+
+        // Times without a date are combined with the last date, which is a day behind after midnight.
+        // Only a short step forward past midnight is taken as the next day, not a jump back in time.
+        if !with_date {
+            let next_day = date_time + Duration::days(1);
+            let step = self.date_time.map(|last| next_day - last);
+            if step
+                .is_some_and(|x| x > Duration::zero() && x <= Duration::minutes(MAX_ROLLOVER_STEP))
+            {
+                date_time = next_day;
+                self.guessed_date = Some(Date {
+                    day: next_day.day() as u8,
+                    month: next_day.month() as u8,
+                    year: next_day.year() as u16,
+                });
+            }
+        }
+
+        self.date_time = Some(date_time);
+        self.clock_offset = Some(self.received - date_time);
+    }
+}
+
+/// Serializes a time zone as its offset from UTC in seconds.
+#[cfg(feature = "serde")]
+fn serialize_offset<S: serde::Serializer>(
+    offset: &Option<FixedOffset>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serde::Serialize::serialize(&offset.map(|x| x.local_minus_utc()), serializer)
+}
+
+/// Serializes a duration in seconds.
+#[cfg(feature = "serde")]
+fn serialize_seconds<S: serde::Serializer>(
+    duration: &Option<Duration>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let seconds = duration.map(|x| x.num_microseconds().unwrap_or(i64::MAX) as f64 / 1e6);
+    serde::Serialize::serialize(&seconds, serializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{checksum, Message};
+
+    use super::*;
+
+    const POSITION: &str = "6009.000,N,02457.000,E";
+
+    fn handle(location: &mut Location, sentence: &str) {
+        let line = format!("${sentence}*{:02X}", checksum(sentence.as_bytes()));
+        let msg = Message::parse(line.as_bytes()).unwrap();
+        location.handle(&msg.message, Utc::now());
+    }
+
+    fn rmc(location: &mut Location, time: &str, date: &str) {
+        handle(
+            location,
+            &format!("GPRMC,{time},A,{POSITION},5.0,90.0,{date},,,A"),
+        );
+    }
+
+    fn gll(location: &mut Location, time: &str) {
+        handle(location, &format!("GPGLL,{POSITION},{time},A,A"));
+    }
+
+    fn date_time(location: &Location) -> String {
+        location
+            .date_time
+            .unwrap()
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string()
+    }
+
+    #[test]
+    fn times_without_a_date_roll_over_at_midnight() {
+        let mut location = Location::new();
+        rmc(&mut location, "235959.00", "010224");
+        assert_eq!(date_time(&location), "2024-02-01 23:59:59");
+
+        gll(&mut location, "000001.00");
+        assert_eq!(date_time(&location), "2024-02-02 00:00:01");
+        // The guessed date is kept for the following times
+        gll(&mut location, "000002.00");
+        assert_eq!(date_time(&location), "2024-02-02 00:00:02");
+
+        rmc(&mut location, "000003.00", "020224");
+        assert_eq!(date_time(&location), "2024-02-02 00:00:03");
+        gll(&mut location, "000004.00");
+        assert_eq!(date_time(&location), "2024-02-02 00:00:04");
+    }
+
+    #[test]
+    fn times_going_back_do_not_roll_over() {
+        let mut location = Location::new();
+        rmc(&mut location, "235959.00", "010224");
+        gll(&mut location, "235950.00");
+        assert_eq!(date_time(&location), "2024-02-01 23:59:50");
+    }
+
+    #[test]
+    fn long_steps_do_not_roll_over() {
+        let mut location = Location::new();
+        rmc(&mut location, "235959.00", "010224");
+        gll(&mut location, "001500.00");
+        assert_eq!(date_time(&location), "2024-02-01 00:15:00");
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+
+use crate::{log::Log, ubx::Packet};
+
+use self::{
+    accuracy::Accuracy, bus::Bus, depth::Depth, heading::Heading, location::Location,
+    receiver::Receiver, routes::Routes, satellites::Satellites, sensors::Sensors, water::Water,
+    wind::Wind,
+};
+
+use super::{Message, Sentence};
+
+pub mod accuracy;
+pub mod bus;
+pub mod depth;
+pub mod heading;
+pub mod location;
+pub mod receiver;
+pub mod routes;
+pub mod satellites;
+pub mod sensors;
+pub mod water;
+pub mod wind;
+
+/// Number of samples kept in the history of a value.
+pub const HISTORY_SAMPLES: usize = 60;
+
+/// Decoded data from every sentence received.
+/// Events such as a new source or shallow water go to the [`Log`], nothing is printed.
+/// With the `serde` feature, the stores serialize to a snapshot of their current values.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Store {
+    #[cfg_attr(feature = "serde", serde(skip))]
+    log: Log,
+    pub satellites: Satellites,
+    pub location: Location,
+    pub heading: Heading,
+    pub depth: Depth,
+    pub wind: Wind,
+    pub water: Water,
+    pub sensors: Sensors,
+    pub accuracy: Accuracy,
+    pub routes: Routes,
+    pub receiver: Receiver,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub bus: Bus,
+    /// Sources named by TAG blocks.
+    pub sources: BTreeMap<String, Source>,
+}
+
+/// A source named by TAG blocks, such as an instrument behind a multiplexer.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Source {
+    /// When its last sentence was received, the TAG block time if it has one, otherwise the system clock.
+    pub last_seen: DateTime<Utc>,
+}
+
+impl Store {
+    pub fn new(log: Log) -> Self {
+        Self {
+            log,
+            satellites: Satellites::new(),
+            location: Location::new(),
+            heading: Heading::new(),
+            depth: Depth::new(),
+            wind: Wind::new(),
+            water: Water::new(),
+            sensors: Sensors::new(),
+            accuracy: Accuracy::new(),
+            routes: Routes::new(),
+            receiver: Receiver::new(),
+            bus: Bus::new(),
+            sources: BTreeMap::new(),
+        }
+    }
+
+    pub fn handle(&mut self, message: Message) {
+        // The TAG block time is when the sentence was received, the system clock is only a fallback.
+        // The clock offset is still against the system clock, it is meant to check that clock
+        let now = Utc::now();
+        let received = message.time().unwrap_or(now);
+        if let Some(source) = message.source() {
+            let entry = Source {
+                last_seen: received,
+            };
+            if self.sources.insert(source.to_owned(), entry).is_none() {
+                self.log.info(format!("New source: {source}"));
+            }
+        }
+
+        let sentence = message.message;
+        if let Sentence::Txt(txt) = &sentence {
+            self.log.info(format!("GPS MESSAGE: {}", txt.message));
+        }
+
+        self.satellites.handle(&sentence);
+        self.location.handle(&sentence, now);
+        self.heading.handle(&sentence);
+        self.wind.handle(&sentence, &self.location, &self.heading);
+        self.water.handle(&sentence);
+        self.sensors.handle(&sentence);
+        self.accuracy.handle(&sentence);
+        self.routes.handle(&sentence);
+        self.receiver.handle(&sentence);
+        self.update_routes();
+
+        let was_shallow = self.depth.is_shallow();
+        self.depth.handle(&sentence);
+        if !was_shallow && self.depth.is_shallow() {
+            let depth = self.depth.depth().unwrap_or_default();
+            self.log.warning(format!("Shallow water: {depth:.1}m"));
+        }
+    }
+
+    /// Handles a UBX packet, received alongside the sentences.
+    pub fn handle_ubx(&mut self, packet: Packet) {
+        self.satellites.handle_ubx(&packet);
+        self.location.handle_ubx(&packet);
+        self.receiver.handle_ubx(&packet);
+        self.update_routes();
+    }
+
+    fn update_routes(&mut self) {
+        if let Some(waypoint) = self.routes.update(&self.location) {
+            self.log.info(format!("Arrived at waypoint {waypoint}"));
+        }
+    }
+}
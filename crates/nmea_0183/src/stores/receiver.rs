@@ -0,0 +1,80 @@
+use std::{collections::VecDeque, time::Instant};
+
+use crate::{
+    proprietary::{
+        mtk::{Acknowledge, AcknowledgeFlag, MtkSentence, OutputRates, Release, SystemMessage},
+        Proprietary,
+    },
+    ubx::{packets::receiver_version::ReceiverVersion, Packet},
+    Sentence,
+};
+
+/// Number of acknowledgements to keep.
+const MAX_ACKNOWLEDGEMENTS: usize = 16;
+
+/// Configuration reported by the receiver in response to queries.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Receiver {
+    /// Time between fixes in milliseconds.
+    pub fix_interval: Option<u16>,
+    pub output_rates: Option<OutputRates>,
+    pub sbas_enabled: Option<bool>,
+    pub release: Option<Release>,
+    pub system_message: Option<SystemMessage>,
+    /// Version reported by a u-blox receiver.
+    pub version: Option<ReceiverVersion>,
+    /// The last acknowledged commands and when they were acknowledged, oldest first.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub acknowledgements: VecDeque<(Instant, Acknowledge)>,
+}
+
+impl Receiver {
+    pub fn new() -> Self {
+        Self {
+            fix_interval: None,
+            output_rates: None,
+            sbas_enabled: None,
+            release: None,
+            system_message: None,
+            version: None,
+            acknowledgements: VecDeque::new(),
+        }
+    }
+
+    /// Gets the acknowledgement of a command received after the given time.
+    pub fn acknowledgement(&self, command: u16, since: Instant) -> Option<AcknowledgeFlag> {
+        self.acknowledgements
+            .iter()
+            .rev()
+            .take_while(|(time, _)| *time >= since)
+            .find(|(_, ack)| ack.command == command)
+            .map(|(_, ack)| ack.flag)
+    }
+
+    pub fn handle_ubx(&mut self, packet: &Packet) {
+        if let Packet::MonVer(version) = packet {
+            self.version = Some(version.clone());
+        }
+    }
+
+    pub fn handle(&mut self, sentence: &Sentence) {
+        let Sentence::Proprietary(Proprietary::Mtk(sentence)) = sentence else {
+            return;
+        };
+
+        match sentence {
+            MtkSentence::Acknowledge(ack) => {
+                if self.acknowledgements.len() >= MAX_ACKNOWLEDGEMENTS {
+                    self.acknowledgements.pop_front();
+                }
+                self.acknowledgements.push_back((Instant::now(), *ack));
+            }
+            MtkSentence::SystemMessage(message) => self.system_message = Some(*message),
+            MtkSentence::FixInterval(interval) => self.fix_interval = Some(*interval),
+            MtkSentence::SbasEnabled(enabled) => self.sbas_enabled = Some(*enabled),
+            MtkSentence::OutputRates(rates) => self.output_rates = Some(rates.clone()),
+            MtkSentence::Release(release) => self.release = Some(release.clone()),
+            MtkSentence::Text(_) | MtkSentence::Other { .. } => {}
+        }
+    }
+}
@@ -0,0 +1,571 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use crate::{
+    coordinate::Coordinate,
+    faa_mode::FaaMode,
+    misc::delayed::Delayed,
+    navigation::{ArrivalStatus, Bearing, BearingReference, SteerDirection},
+    packets::{
+        autopilot::Autopilot,
+        bearing_distance_waypoint::BearingDistanceWaypoint,
+        bearing_origin_destination::BearingOriginDestination,
+        cross_track_error::CrossTrackError,
+        geographic_position::Status,
+        recommended_navigation::RecommendedNavigation,
+        route::{Route, RouteMode},
+        waypoint_location::WaypointLocation,
+    },
+    talker::Talker,
+    writer::Writer,
+    Message, Sentence, MAX_SENTENCE_LENGTH,
+};
+
+use super::location::Location;
+
+/// Navigation data from a chartplotter older than this is not used.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Distance to a waypoint at which it counts as reached, in nautical miles.
+pub const ARRIVAL_RADIUS: f32 = 0.05;
+
+/// Mean radius of the earth in nautical miles.
+const EARTH_RADIUS: f64 = 3440.065;
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Waypoint {
+    pub latitude: Coordinate,
+    pub longitude: Coordinate,
+}
+
+/// The route being navigated.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ActiveRoute {
+    pub route: String,
+    /// Index of the waypoint being navigated to.
+    pub leg: usize,
+    /// Whether the last waypoint was reached.
+    pub arrived: bool,
+}
+
+/// Navigation to a waypoint, calculated from the active route or received from a chartplotter.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Navigation {
+    pub origin: Option<String>,
+    pub destination: Option<String>,
+    /// Cross track error in nautical miles, positive when right of the track.
+    pub cross_track_error: Option<f32>,
+    /// Bearing from the origin to the destination in degrees, true.
+    pub track: Option<f32>,
+    /// Bearing to the destination in degrees, true.
+    pub bearing: Option<f32>,
+    /// Range to the destination in nautical miles.
+    pub range: Option<f32>,
+    /// Velocity towards the destination in knots.
+    pub closing_velocity: Option<f32>,
+    /// Heading to steer in degrees, true.
+    pub heading_to_steer: Option<f32>,
+    /// Whether the arrival circle of the destination was entered.
+    pub arrival_circle: bool,
+    /// Whether the perpendicular through the destination was passed.
+    pub perpendicular_passed: bool,
+}
+
+impl Navigation {
+    pub fn arrived(&self) -> bool {
+        self.arrival_circle || self.perpendicular_passed
+    }
+}
+
+/// A route that is still being received.
+struct PartialRoute {
+    route: String,
+    mode: RouteMode,
+    total: u8,
+    received: u8,
+    waypoints: Vec<String>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Routes {
+    /// Known waypoints by name, from WPL sentences.
+    pub waypoints: BTreeMap<String, Waypoint>,
+    /// Known routes by name, from complete RTE messages.
+    pub routes: BTreeMap<String, Box<[String]>>,
+    /// The remaining waypoints of the route followed by a chartplotter, from working RTE messages.
+    pub working: Option<(String, Box<[String]>)>,
+    /// Our route being navigated, used to generate sentences.
+    pub active: Option<ActiveRoute>,
+    /// Navigation received from a chartplotter, merged from RMB, APB, BOD, BWC and XTE.
+    pub received: Delayed<Navigation>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    partial: Option<PartialRoute>,
+}
+
+impl Routes {
+    pub fn new() -> Self {
+        Self {
+            waypoints: BTreeMap::new(),
+            routes: BTreeMap::new(),
+            working: None,
+            active: None,
+            received: Delayed::new().with_timeout(TIMEOUT),
+            partial: None,
+        }
+    }
+
+    pub fn handle(&mut self, sentence: &Sentence) {
+        match sentence {
+            Sentence::Wpl(sentence) => {
+                self.waypoints.insert(
+                    sentence.waypoint.clone(),
+                    Waypoint {
+                        latitude: sentence.latitude,
+                        longitude: sentence.longitude,
+                    },
+                );
+            }
+            Sentence::Rte(sentence) => self.handle_route(sentence),
+            Sentence::Rmb(sentence) => self.receive(|navigation| {
+                navigation.origin = Some(sentence.origin.clone());
+                navigation.destination = Some(sentence.destination.clone());
+                navigation.cross_track_error =
+                    signed_cross_track_error(sentence.cross_track_error, sentence.steer);
+                navigation.bearing = sentence.bearing;
+                navigation.range = sentence.range;
+                navigation.closing_velocity = sentence.closing_velocity;
+                navigation.arrival_circle = sentence.arrival == ArrivalStatus::Arrived;
+            }),
+            Sentence::Apb(sentence) => self.receive(|navigation| {
+                navigation.destination = Some(sentence.destination.clone());
+                navigation.cross_track_error =
+                    signed_cross_track_error(sentence.cross_track_error, sentence.steer);
+                navigation.track = true_bearing(sentence.bearing_origin_destination);
+                navigation.bearing = true_bearing(sentence.bearing_to_destination);
+                navigation.heading_to_steer = true_bearing(sentence.heading_to_steer);
+                navigation.arrival_circle = sentence.arrival_circle == ArrivalStatus::Arrived;
+                navigation.perpendicular_passed = sentence.perpendicular == ArrivalStatus::Arrived;
+            }),
+            Sentence::Bod(sentence) => self.receive(|navigation| {
+                navigation.origin = Some(sentence.origin.clone()).filter(|x| !x.is_empty());
+                navigation.destination = Some(sentence.destination.clone());
+                navigation.track = sentence.bearing_true;
+            }),
+            Sentence::Bwc(sentence) => self.receive(|navigation| {
+                navigation.destination = Some(sentence.waypoint.clone());
+                navigation.bearing = sentence.bearing_true;
+                navigation.range = sentence.distance;
+            }),
+            Sentence::Xte(sentence) => {
+                if let Status::DataValid = sentence.status {
+                    self.receive(|navigation| {
+                        navigation.cross_track_error =
+                            signed_cross_track_error(sentence.cross_track_error, sentence.steer);
+                    })
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Merges received data into the current navigation.
+    fn receive(&mut self, update: impl FnOnce(&mut Navigation)) {
+        let mut navigation = self.received.get().ok().cloned().unwrap_or_default();
+        update(&mut navigation);
+        self.received.update(navigation);
+    }
+
+    fn handle_route(&mut self, sentence: &Route) {
+        if sentence.number == 1 {
+            self.partial = Some(PartialRoute {
+                route: sentence.route.clone(),
+                mode: sentence.mode,
+                total: sentence.total,
+                received: 0,
+                waypoints: Vec::new(),
+            });
+        }
+
+        // Drop routes with missing or out of order sentences
+        let Some(partial) = self.partial.as_mut().filter(|x| {
+            x.route == sentence.route
+                && x.total == sentence.total
+                && x.received + 1 == sentence.number
+        }) else {
+            self.partial = None;
+            return;
+        };
+
+        partial.received += 1;
+        partial.waypoints.extend(sentence.waypoints.iter().cloned());
+        if partial.received < partial.total {
+            return;
+        }
+
+        let partial = self.partial.take().unwrap();
+        let waypoints = partial.waypoints.into_boxed_slice();
+        match partial.mode {
+            RouteMode::Complete => {
+                self.routes.insert(partial.route, waypoints);
+            }
+            RouteMode::Working => self.working = Some((partial.route, waypoints)),
+        }
+    }
+
+    /// Starts navigating a known route from its first leg.
+    pub fn activate(&mut self, route: &str) {
+        let Some(waypoints) = self.routes.get(route) else {
+            return;
+        };
+
+        self.active = Some(ActiveRoute {
+            route: route.to_owned(),
+            leg: if waypoints.len() > 1 { 1 } else { 0 },
+            arrived: false,
+        });
+    }
+
+    pub fn deactivate(&mut self) {
+        self.active = None;
+    }
+
+    /// Gets the names of the origin (if any) and destination of the current leg of the active route.
+    pub fn leg(&self) -> Option<(Option<&str>, &str)> {
+        let active = self.active.as_ref()?;
+        let waypoints = self.routes.get(&active.route)?;
+        let destination = waypoints.get(active.leg)?;
+        let origin = active.leg.checked_sub(1).map(|x| waypoints[x].as_str());
+        Some((origin, destination))
+    }
+
+    /// Calculates the navigation along the current leg of the active route.
+    pub fn navigation(&self, location: &Location) -> Option<Navigation> {
+        if !matches!(location.status, Status::DataValid) {
+            return None;
+        }
+
+        let (origin, destination) = self.leg()?;
+        let position = (location.latitude, location.longitude);
+        let target = self.waypoints.get(destination)?;
+        let (bearing, range) = bearing_distance(position, (target.latitude, target.longitude));
+
+        let mut navigation = Navigation {
+            origin: origin.map(str::to_owned),
+            destination: Some(destination.to_owned()),
+            bearing: Some(bearing),
+            range: Some(range),
+            heading_to_steer: Some(bearing),
+            closing_velocity: location
+                .course
+                .zip(location.speed)
+                .map(|(course, speed)| speed * (course - bearing).to_radians().cos()),
+            arrival_circle: range < ARRIVAL_RADIUS,
+            ..Default::default()
+        };
+
+        let Some(start) = origin.and_then(|x| self.waypoints.get(x)) else {
+            return Some(navigation);
+        };
+
+        // Cross track distance from the great circle between the origin and destination
+        let start = (start.latitude, start.longitude);
+        let (track, _) = bearing_distance(start, (target.latitude, target.longitude));
+        let (from_start, distance) = bearing_distance(start, position);
+        let angle = f64::from(from_start - track).to_radians();
+        let distance = f64::from(distance) / EARTH_RADIUS;
+        let cross_track_error = ((distance.sin() * angle.sin()).asin() * EARTH_RADIUS) as f32;
+
+        navigation.track = Some(track);
+        navigation.cross_track_error = Some(cross_track_error);
+        // The perpendicular was passed when the destination is behind relative to the track
+        let relative_bearing = (bearing - track + 540.0).rem_euclid(360.0) - 180.0;
+        navigation.perpendicular_passed = relative_bearing.abs() > 90.0;
+        Some(navigation)
+    }
+
+    /// Moves to the next leg when the destination of the current one was reached.
+    /// Returns the name of the reached waypoint.
+    pub fn update(&mut self, location: &Location) -> Option<String> {
+        let navigation = self.navigation(location)?;
+        if !navigation.arrived() {
+            return None;
+        }
+
+        let legs = self.routes.get(&self.active.as_ref()?.route)?.len();
+        let active = self.active.as_mut()?;
+        if active.leg + 1 < legs {
+            active.leg += 1;
+        } else if !active.arrived {
+            active.arrived = true;
+        } else {
+            return None;
+        }
+
+        navigation.destination
+    }
+
+    /// Generates the WPL and RTE sentences of the active route.
+    pub fn route_sentences(&self, talker: [u8; 2]) -> Vec<Message> {
+        let Some(active) = &self.active else {
+            return Vec::new();
+        };
+        let Some(waypoints) = self.routes.get(&active.route) else {
+            return Vec::new();
+        };
+
+        let mut out = Vec::new();
+        for name in waypoints.iter() {
+            if let Some(waypoint) = self.waypoints.get(name) {
+                out.push(Sentence::Wpl(WaypointLocation {
+                    latitude: waypoint.latitude,
+                    longitude: waypoint.longitude,
+                    waypoint: name.clone(),
+                }));
+            }
+        }
+
+        // Split the waypoints over as few sentences as fit, assuming two digit sentence numbers
+        let mut groups: Vec<Vec<String>> = vec![Vec::new()];
+        for name in waypoints.iter() {
+            let group = groups.last_mut().unwrap();
+            group.push(name.clone());
+
+            let mut writer = Writer::new();
+            route_sentence(99, 99, &active.route, group).write(&mut writer);
+            if group.len() > 1 && writer.finish("GPRTE").len() > MAX_SENTENCE_LENGTH {
+                let name = group.pop().unwrap();
+                groups.push(vec![name]);
+            }
+        }
+
+        let total = groups.len() as u8;
+        for (i, group) in groups.iter().enumerate() {
+            out.push(Sentence::Rte(route_sentence(
+                total,
+                i as u8 + 1,
+                &active.route,
+                group,
+            )));
+        }
+
+        out.into_iter()
+            .map(|message| Message {
+                talker: Talker::Id(talker),
+                tag_block: None,
+                message,
+            })
+            .collect()
+    }
+
+    /// Generates the RMB, APB, XTE, BOD and BWC sentences for the current leg of the active route.
+    pub fn navigation_sentences(
+        &self,
+        location: &Location,
+        variation: Option<f32>,
+        talker: [u8; 2],
+    ) -> Vec<Message> {
+        let Some(navigation) = self.navigation(location) else {
+            return Vec::new();
+        };
+        let Some(destination) = navigation.destination.clone() else {
+            return Vec::new();
+        };
+        let Some(target) = self.waypoints.get(&destination) else {
+            return Vec::new();
+        };
+
+        let origin = navigation.origin.clone().unwrap_or_default();
+        let magnetic = |bearing: Option<f32>| Some((bearing? - variation?).rem_euclid(360.0));
+        let true_bearing = |degree: Option<f32>| {
+            degree.map(|degree| Bearing {
+                degree,
+                reference: BearingReference::True,
+            })
+        };
+        let arrival = |arrived: bool| match arrived {
+            true => ArrivalStatus::Arrived,
+            false => ArrivalStatus::NotArrived,
+        };
+        let cross_track_error = navigation.cross_track_error.map(f32::abs);
+        let steer = navigation.cross_track_error.map(|x| match x > 0.0 {
+            true => SteerDirection::Left,
+            false => SteerDirection::Right,
+        });
+
+        let sentences = [
+            Sentence::Rmb(RecommendedNavigation {
+                status: Status::DataValid,
+                cross_track_error,
+                steer,
+                origin: origin.clone(),
+                destination: destination.clone(),
+                destination_latitude: Some(target.latitude),
+                destination_longitude: Some(target.longitude),
+                range: navigation.range,
+                bearing: navigation.bearing,
+                closing_velocity: navigation.closing_velocity,
+                arrival: arrival(navigation.arrived()),
+                mode: Some(FaaMode::Autonomous),
+            }),
+            Sentence::Apb(Autopilot {
+                status: Status::DataValid,
+                cycle_lock: Status::DataValid,
+                cross_track_error,
+                steer,
+                arrival_circle: arrival(navigation.arrival_circle),
+                perpendicular: arrival(navigation.perpendicular_passed),
+                bearing_origin_destination: true_bearing(navigation.track),
+                destination: destination.clone(),
+                bearing_to_destination: true_bearing(navigation.bearing),
+                heading_to_steer: true_bearing(navigation.heading_to_steer),
+                mode: Some(FaaMode::Autonomous),
+            }),
+            Sentence::Xte(CrossTrackError {
+                status: Status::DataValid,
+                cycle_lock: Status::DataValid,
+                cross_track_error,
+                steer,
+                mode: Some(FaaMode::Autonomous),
+            }),
+            Sentence::Bod(BearingOriginDestination {
+                bearing_true: navigation.track,
+                bearing_magnetic: magnetic(navigation.track),
+                destination: destination.clone(),
+                origin,
+            }),
+            Sentence::Bwc(BearingDistanceWaypoint {
+                time: Some(location.time),
+                latitude: Some(target.latitude),
+                longitude: Some(target.longitude),
+                bearing_true: navigation.bearing,
+                bearing_magnetic: magnetic(navigation.bearing),
+                distance: navigation.range,
+                waypoint: destination,
+                mode: Some(FaaMode::Autonomous),
+            }),
+        ];
+
+        sentences
+            .into_iter()
+            .map(|message| Message {
+                talker: Talker::Id(talker),
+                tag_block: None,
+                message,
+            })
+            .collect()
+    }
+}
+
+fn route_sentence(total: u8, number: u8, route: &str, waypoints: &[String]) -> Route {
+    Route {
+        total,
+        number,
+        mode: RouteMode::Complete,
+        route: route.to_owned(),
+        waypoints: waypoints.into(),
+    }
+}
+
+/// Combines a cross track error with the direction to steer, positive when right of the track.
+fn signed_cross_track_error(error: Option<f32>, steer: Option<SteerDirection>) -> Option<f32> {
+    match steer {
+        Some(SteerDirection::Right) => error.map(|x| -x),
+        _ => error,
+    }
+}
+
+fn true_bearing(bearing: Option<Bearing>) -> Option<f32> {
+    bearing
+        .filter(|x| x.reference == BearingReference::True)
+        .map(|x| x.degree)
+}
+
+/// Gets the initial great circle bearing (degrees true) and distance (nautical miles) between two positions.
+fn bearing_distance(from: (Coordinate, Coordinate), to: (Coordinate, Coordinate)) -> (f32, f32) {
+    let (lat1, lon1) = (
+        f64::from(from.0.degree).to_radians(),
+        f64::from(from.1.degree).to_radians(),
+    );
+    let (lat2, lon2) = (
+        f64::from(to.0.degree).to_radians(),
+        f64::from(to.1.degree).to_radians(),
+    );
+    let delta_lon = lon2 - lon1;
+
+    let y = delta_lon.sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * delta_lon.cos();
+    let bearing = y.atan2(x).to_degrees().rem_euclid(360.0);
+
+    let a = ((lat2 - lat1) / 2.0).sin().powi(2)
+        + lat1.cos() * lat2.cos() * (delta_lon / 2.0).sin().powi(2);
+    let distance = 2.0 * a.sqrt().atan2((1.0 - a).sqrt()) * EARTH_RADIUS;
+
+    (bearing as f32, distance as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route_with_waypoints(count: usize) -> (Routes, Vec<String>) {
+        let waypoints: Vec<_> = (1..=count).map(|x| format!("WAYPT{x:02}")).collect();
+        let mut routes = Routes::new();
+        routes
+            .routes
+            .insert("HOME".to_owned(), waypoints.clone().into_boxed_slice());
+        routes.activate("HOME");
+        (routes, waypoints)
+    }
+
+    /// Encodes the RTE sentences of the active route, as sent.
+    fn encoded_routes(routes: &Routes) -> Vec<String> {
+        routes
+            .route_sentences(*b"GP")
+            .iter()
+            .filter(|x| matches!(x.message, Sentence::Rte(_)))
+            .map(|x| x.encode().unwrap())
+            .collect()
+    }
+
+    fn receive(routes: &mut Routes, line: &str) {
+        let msg = Message::parse(line.trim_end().as_bytes()).unwrap();
+        routes.handle(&msg.message);
+    }
+
+    #[test]
+    fn splits_long_routes_into_sentences_that_fit() {
+        let (routes, waypoints) = route_with_waypoints(30);
+        let lines = encoded_routes(&routes);
+        assert!(
+            lines.len() > 1,
+            "30 waypoints should not fit in one sentence"
+        );
+        assert!(lines.iter().all(|x| x.len() <= MAX_SENTENCE_LENGTH));
+
+        let mut received = Routes::new();
+        for line in &lines {
+            receive(&mut received, line);
+        }
+        assert_eq!(received.routes["HOME"].as_ref(), waypoints.as_slice());
+    }
+
+    #[test]
+    fn short_routes_fit_in_one_sentence() {
+        let (routes, _) = route_with_waypoints(3);
+        assert_eq!(encoded_routes(&routes).len(), 1);
+    }
+
+    #[test]
+    fn drops_routes_with_missing_sentences() {
+        let (routes, _) = route_with_waypoints(30);
+        let lines = encoded_routes(&routes);
+
+        let mut received = Routes::new();
+        for line in lines.iter().step_by(2) {
+            receive(&mut received, line);
+        }
+        assert!(received.routes.is_empty());
+    }
+}
@@ -0,0 +1,91 @@
+use std::{collections::VecDeque, mem};
+
+use crate::{
+    misc::average::Average,
+    packets::satellites_in_view::{Satellite, SatellitesInView},
+    stores::HISTORY_SAMPLES,
+    ubx::{packets::satellite_info::SatelliteInfo, Packet},
+    Sentence,
+};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Satellites {
+    /// Number of satellites in view.
+    pub in_view: u16,
+    /// The satellites in view.
+    pub satellites: Vec<Satellite>,
+    pub avg_sdr_history: VecDeque<f32>,
+
+    /// Holds the new satellites until the last sentence is received.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    new_satellites: Vec<Satellite>,
+}
+
+impl Satellites {
+    pub fn new() -> Self {
+        Self {
+            in_view: 0,
+            satellites: Vec::new(),
+            avg_sdr_history: VecDeque::new(),
+            new_satellites: Vec::new(),
+        }
+    }
+
+    pub fn connected(&self) -> u8 {
+        self.satellites.iter().filter(|x| x.snr.is_some()).count() as u8
+    }
+
+    pub fn handle(&mut self, sentence: &Sentence) {
+        if let Sentence::Gsv(sentence) = sentence {
+            self.handle_inner(sentence);
+        }
+    }
+
+    pub fn handle_ubx(&mut self, packet: &Packet) {
+        if let Packet::NavSat(packet) = packet {
+            self.handle_satellite_info(packet);
+        }
+    }
+
+    fn handle_inner(&mut self, sentence: &SatellitesInView) {
+        self.in_view = sentence.in_view;
+        self.new_satellites
+            .extend(sentence.satellites.iter().cloned());
+
+        if sentence.total_in_group == sentence.sentence_number {
+            mem::swap(&mut self.new_satellites, &mut self.satellites);
+            self.new_satellites.clear();
+            self.update_history();
+        }
+    }
+
+    /// NAV-SAT contains all satellites in a single packet.
+    fn handle_satellite_info(&mut self, packet: &SatelliteInfo) {
+        self.in_view = packet.satellites.len() as u16;
+        self.satellites = packet
+            .satellites
+            .iter()
+            .map(|x| Satellite {
+                id: x.nmea_id(),
+                elevation: x.elevation,
+                azimuth: x.azimuth,
+                snr: Some(x.cno).filter(|x| *x > 0),
+            })
+            .collect();
+        self.update_history();
+    }
+
+    fn update_history(&mut self) {
+        let avg = self
+            .satellites
+            .iter()
+            .flat_map(|x| x.snr)
+            .map(|x| x as f32)
+            .average();
+        self.avg_sdr_history.push_back(avg);
+
+        while self.avg_sdr_history.len() > HISTORY_SAMPLES {
+            self.avg_sdr_history.pop_front();
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::{collections::BTreeMap, collections::VecDeque, time::Duration};
+
+use crate::{
+    misc::delayed::Delayed,
+    packets::transducer_measurement::{Measurement, TransducerType},
+    stores::HISTORY_SAMPLES,
+    Sentence,
+};
+
+/// Sensor values older than this are shown as outdated.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Named sensors from transducer measurement (XDR) sentences.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Sensors {
+    /// Sensors keyed by their name, or type and index if unnamed.
+    pub sensors: BTreeMap<String, Sensor>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Sensor {
+    pub kind: TransducerType,
+    pub unit: Option<char>,
+    pub value: Delayed<f32>,
+    pub history: VecDeque<f32>,
+    /// Lowest value received.
+    pub min: f32,
+    /// Highest value received.
+    pub max: f32,
+}
+
+impl Sensors {
+    pub fn new() -> Self {
+        Self {
+            sensors: BTreeMap::new(),
+        }
+    }
+
+    pub fn handle(&mut self, sentence: &Sentence) {
+        if let Sentence::Xdr(sentence) = sentence {
+            for (i, measurement) in sentence.measurements.iter().enumerate() {
+                self.handle_measurement(i, measurement);
+            }
+        }
+    }
+
+    fn handle_measurement(&mut self, index: usize, measurement: &Measurement) {
+        let Some(value) = measurement.value else {
+            return;
+        };
+
+        let name = match measurement.name.is_empty() {
+            true => format!("{:?} {}", measurement.kind, index + 1),
+            false => measurement.name.clone(),
+        };
+
+        let sensor = self.sensors.entry(name).or_insert_with(|| Sensor {
+            kind: measurement.kind,
+            unit: measurement.unit,
+            value: Delayed::new().with_timeout(TIMEOUT),
+            history: VecDeque::new(),
+            min: value,
+            max: value,
+        });
+
+        sensor.kind = measurement.kind;
+        sensor.unit = measurement.unit;
+        sensor.value.update(value);
+        sensor.min = sensor.min.min(value);
+        sensor.max = sensor.max.max(value);
+
+        sensor.history.push_back(value);
+        while sensor.history.len() > HISTORY_SAMPLES {
+            sensor.history.pop_front();
+        }
+    }
+}
+
+impl Sensor {
+    /// Gets the symbol of this sensor's unit.
+    pub fn unit_symbol(&self) -> String {
+        self.unit
+            .map(|x| self.kind.unit_symbol(x))
+            .unwrap_or_default()
+    }
+
+    /// Gets the range to show on a gauge.
+    /// Uses the full range for angles and percentages, otherwise the range of received values.
+    pub fn gauge_range(&self) -> (f32, f32) {
+        match (self.kind, self.unit) {
+            (TransducerType::Angle, _) => (-180.0, 180.0),
+            (TransducerType::Humidity, Some('P')) => (0.0, 100.0),
+            (TransducerType::Switch, _) => (0.0, 1.0),
+            _ if self.min == self.max => (self.min.min(0.0), self.max.max(1.0)),
+            _ => (self.min.min(0.0), self.max),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use std::time::Duration;
+
+use crate::{misc::delayed::Delayed, packets::distance_log::DistanceLog, Sentence};
+
+use super::{heading::Heading, location::Location};
+
+/// Water data older than this is not used.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Water {
+    /// Speed through water in knots.
+    pub speed: Delayed<f32>,
+    /// True heading sent along with the speed through water.
+    pub heading: Delayed<f32>,
+    /// Water temperature in degrees Celsius.
+    pub temperature: Delayed<f32>,
+    /// Total distance through water, nautical miles.
+    pub total_distance: Option<f32>,
+    /// Distance through water since reset, nautical miles.
+    pub trip_distance: Option<f32>,
+    /// Total distance over ground, nautical miles.
+    pub total_ground_distance: Option<f32>,
+    /// Distance over ground since reset, nautical miles.
+    pub trip_ground_distance: Option<f32>,
+}
+
+/// The water current, found from the difference between the motion over ground and through water.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Current {
+    /// Direction the current is flowing towards, in degrees true.
+    pub set: f32,
+    /// Speed of the current in knots.
+    pub drift: f32,
+}
+
+impl Water {
+    pub fn new() -> Self {
+        Self {
+            speed: Delayed::new().with_timeout(TIMEOUT),
+            heading: Delayed::new().with_timeout(TIMEOUT),
+            temperature: Delayed::new().with_timeout(TIMEOUT),
+            total_distance: None,
+            trip_distance: None,
+            total_ground_distance: None,
+            trip_ground_distance: None,
+        }
+    }
+
+    /// Calculates the set and drift of the current.
+    /// Needs the speed through water, a heading and the course and speed over ground.
+    pub fn current(&self, location: &Location, heading: &Heading) -> Option<Current> {
+        let speed = *self.speed.get().ok()?;
+        let heading = heading.heading().or(self.heading.get().ok().copied())?;
+        let (course, ground_speed) = (location.course?, location.speed?);
+
+        let (heading, course) = (heading.to_radians(), course.to_radians());
+        let east = ground_speed * course.sin() - speed * heading.sin();
+        let north = ground_speed * course.cos() - speed * heading.cos();
+
+        Some(Current {
+            set: east.atan2(north).to_degrees().rem_euclid(360.0),
+            drift: east.hypot(north),
+        })
+    }
+
+    pub fn handle(&mut self, sentence: &Sentence) {
+        match sentence {
+            Sentence::Vhw(sentence) => {
+                if let Some(speed) = sentence.speed() {
+                    self.speed.update(speed);
+                }
+                if let Some(heading) = sentence.heading_true {
+                    self.heading.update(heading);
+                }
+            }
+            Sentence::Vlw(sentence) => self.handle_distance_log(sentence),
+            Sentence::Mtw(sentence) => {
+                if let Some(temperature) = sentence.temperature {
+                    self.temperature.update(temperature);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_distance_log(&mut self, sentence: &DistanceLog) {
+        self.total_distance = sentence.total_water;
+        self.trip_distance = sentence.trip_water;
+        self.total_ground_distance = sentence.total_ground;
+        self.trip_ground_distance = sentence.trip_ground;
+    }
+}
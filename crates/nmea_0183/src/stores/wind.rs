@@ -0,0 +1,264 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    misc::delayed::Delayed,
+    packets::{
+        geographic_position::Status,
+        wind_speed_angle::{WindReference, WindSpeedAngle},
+    },
+    Sentence,
+};
+
+use super::{heading::Heading, location::Location};
+
+/// Wind data older than this is not used.
+const TIMEOUT: Duration = Duration::from_secs(5);
+/// Time window used for the average and gust values.
+const AVERAGE_WINDOW: Duration = Duration::from_secs(120);
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Wind {
+    /// Apparent wind angle in degrees from the bow, -180 to 180, negative is port.
+    pub apparent_angle: Delayed<f32>,
+    /// Apparent wind speed in knots.
+    pub apparent_speed: Delayed<f32>,
+    /// True wind angle in degrees from the bow, -180 to 180, negative is port.
+    pub true_angle: Delayed<f32>,
+    /// Direction the true wind is blowing from, in degrees true.
+    pub true_direction: Delayed<f32>,
+    /// True wind speed in knots.
+    pub true_speed: Delayed<f32>,
+    /// If the true wind is calculated from the apparent wind and vessel motion,
+    /// rather than sent by the instrument.
+    pub true_calculated: bool,
+
+    /// True wind samples in the average window, as (time, speed, direction).
+    #[cfg_attr(feature = "serde", serde(skip))]
+    samples: VecDeque<(Instant, f32, Option<f32>)>,
+    /// Set while the instrument is sending true wind.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    measured_true: Delayed<()>,
+}
+
+impl Wind {
+    pub fn new() -> Self {
+        Self {
+            apparent_angle: Delayed::new().with_timeout(TIMEOUT),
+            apparent_speed: Delayed::new().with_timeout(TIMEOUT),
+            true_angle: Delayed::new().with_timeout(TIMEOUT),
+            true_direction: Delayed::new().with_timeout(TIMEOUT),
+            true_speed: Delayed::new().with_timeout(TIMEOUT),
+            true_calculated: false,
+            samples: VecDeque::new(),
+            measured_true: Delayed::new().with_timeout(TIMEOUT),
+        }
+    }
+
+    /// Average true wind speed in knots over the last two minutes.
+    pub fn average_speed(&self) -> Option<f32> {
+        if self.samples.is_empty() {
+            return None;
+        }
+
+        let sum = self.samples.iter().map(|x| x.1).sum::<f32>();
+        Some(sum / self.samples.len() as f32)
+    }
+
+    /// Average true wind direction over the last two minutes, weighted by speed.
+    pub fn average_direction(&self) -> Option<f32> {
+        let (east, north) = self
+            .samples
+            .iter()
+            .filter_map(|x| Some((x.1, x.2?.to_radians())))
+            .fold((0.0, 0.0), |(east, north), (speed, direction)| {
+                (
+                    east + speed * direction.sin(),
+                    north + speed * direction.cos(),
+                )
+            });
+
+        if east == 0.0 && north == 0.0 {
+            return None;
+        }
+
+        Some(east.atan2(north).to_degrees().rem_euclid(360.0))
+    }
+
+    /// Highest true wind speed in knots over the last two minutes.
+    pub fn gust(&self) -> Option<f32> {
+        self.samples.iter().map(|x| x.1).reduce(f32::max)
+    }
+
+    pub fn handle(&mut self, sentence: &Sentence, location: &Location, heading: &Heading) {
+        let bow = heading.heading().or(location.course);
+        match sentence {
+            Sentence::Mwv(sentence) => self.handle_speed_angle(sentence, location, bow),
+            Sentence::Vwr(sentence) => {
+                if let (Some(angle), Some(speed)) = (sentence.angle, sentence.speed()) {
+                    self.update_apparent(angle, speed, location, bow);
+                }
+            }
+            Sentence::Vwt(sentence) => {
+                if let (Some(angle), Some(speed)) = (sentence.angle, sentence.speed()) {
+                    self.update_true(Some(angle), bow.map(|x| x + angle), speed);
+                }
+            }
+            Sentence::Mwd(sentence) => {
+                if let (Some(direction), Some(speed)) = (sentence.direction_true, sentence.speed())
+                {
+                    self.update_true(bow.map(|x| direction - x), Some(direction), speed);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_speed_angle(
+        &mut self,
+        sentence: &WindSpeedAngle,
+        location: &Location,
+        bow: Option<f32>,
+    ) {
+        let (Some(angle), Some(speed), Status::DataValid) =
+            (sentence.angle, sentence.speed_knots(), sentence.status)
+        else {
+            return;
+        };
+
+        match sentence.reference {
+            WindReference::Relative => self.update_apparent(angle, speed, location, bow),
+            WindReference::True => self.update_true(Some(angle), bow.map(|x| x + angle), speed),
+        }
+    }
+
+    fn update_apparent(&mut self, angle: f32, speed: f32, location: &Location, bow: Option<f32>) {
+        self.apparent_angle.update(normalize_angle(angle));
+        self.apparent_speed.update(speed);
+
+        // Only calculate the true wind if the instrument is not already sending it
+        if self.measured_true.get().ok().is_some() {
+            return;
+        }
+
+        let (Some(bow), Some(course), Some(boat_speed)) = (bow, location.course, location.speed)
+        else {
+            return;
+        };
+
+        let (direction, true_speed) = true_wind(angle, speed, bow, course, boat_speed);
+        self.true_calculated = true;
+        self.set_true(Some(direction - bow), Some(direction), true_speed);
+    }
+
+    fn update_true(&mut self, angle: Option<f32>, direction: Option<f32>, speed: f32) {
+        self.measured_true.update(());
+        self.true_calculated = false;
+        self.set_true(angle, direction, speed);
+    }
+
+    fn set_true(&mut self, angle: Option<f32>, direction: Option<f32>, speed: f32) {
+        let direction = direction.map(|x| x.rem_euclid(360.0));
+        if let Some(angle) = angle {
+            self.true_angle.update(normalize_angle(angle));
+        }
+        self.true_speed.update(speed);
+        if let Some(direction) = direction {
+            self.true_direction.update(direction);
+        }
+
+        let now = Instant::now();
+        self.samples.push_back((now, speed, direction));
+        while self
+            .samples
+            .front()
+            .is_some_and(|x| now - x.0 > AVERAGE_WINDOW)
+        {
+            self.samples.pop_front();
+        }
+    }
+}
+
+/// Calculates the true wind direction (degrees true) and speed from the apparent wind and vessel motion.
+/// The apparent angle is relative to `bow`, all speeds are in the same unit.
+pub fn true_wind(
+    apparent_angle: f32,
+    apparent_speed: f32,
+    bow: f32,
+    course: f32,
+    boat_speed: f32,
+) -> (f32, f32) {
+    // Velocity of the air as felt on the vessel, as (east, north)
+    let apparent = (bow + apparent_angle).to_radians();
+    let air = (
+        -apparent_speed * apparent.sin(),
+        -apparent_speed * apparent.cos(),
+    );
+
+    // Adding the vessel's velocity gives the velocity of the air over ground
+    let course = course.to_radians();
+    let east = air.0 + boat_speed * course.sin();
+    let north = air.1 + boat_speed * course.cos();
+
+    let direction = (-east).atan2(-north).to_degrees().rem_euclid(360.0);
+    let speed = east.hypot(north);
+    (direction, speed)
+}
+
+/// Normalizes an angle to -180 to 180 degrees.
+fn normalize_angle(angle: f32) -> f32 {
+    let angle = angle.rem_euclid(360.0);
+    if angle > 180.0 {
+        angle - 360.0
+    } else {
+        angle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(value: f32, expected: f32) {
+        assert!(
+            (value - expected).abs() < 1e-3,
+            "{value} should be {expected}"
+        );
+    }
+
+    #[test]
+    fn true_wind_is_apparent_wind_when_stopped() {
+        let (direction, speed) = true_wind(30.0, 10.0, 90.0, 0.0, 0.0);
+        assert_close(direction, 120.0);
+        assert_close(speed, 10.0);
+    }
+
+    #[test]
+    fn true_wind_is_calm_when_motoring_into_still_air() {
+        let (_, speed) = true_wind(0.0, 5.0, 0.0, 0.0, 5.0);
+        assert_close(speed, 0.0);
+    }
+
+    #[test]
+    fn true_wind_removes_vessel_motion() {
+        // 8 knots from the east felt at 6 knots north, as 10 knots from 53.13 degrees
+        let angle = 8f32.atan2(6.0).to_degrees();
+        let (direction, speed) = true_wind(angle, 10.0, 0.0, 0.0, 6.0);
+        assert_close(direction, 90.0);
+        assert_close(speed, 8.0);
+
+        // The same from the west, on the port side of a bow 10 degrees off the course
+        let (direction, speed) = true_wind(10.0 - angle, 10.0, 350.0, 0.0, 6.0);
+        assert_close(direction, 270.0);
+        assert_close(speed, 8.0);
+    }
+
+    #[test]
+    fn normalizes_angles() {
+        assert_close(normalize_angle(330.0), -30.0);
+        assert_close(normalize_angle(-190.0), 170.0);
+        assert_close(normalize_angle(180.0), 180.0);
+    }
+}
@@ -0,0 +1,173 @@
+//! TAG blocks, added in NMEA 0183 version 4.
+//! A sentence can be prefixed by `\code:value,...*hh\`, used by multiplexers and AIS base stations.
+
+use core::str;
+
+use chrono::{DateTime, Utc};
+
+use super::{checksum, error::Nmea0183Error, text, String};
+
+/// UNIX times above this are assumed to be in milliseconds, seconds would be after the year 2286.
+const MILLISECONDS_THRESHOLD: i64 = 10_000_000_000;
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TagBlock {
+    /// `c:` Time the sentence was received.
+    pub time: Option<DateTime<Utc>>,
+    /// `s:` Source, the station or device the sentence came from.
+    pub source: Option<String>,
+    /// `d:` Destination.
+    pub destination: Option<String>,
+    /// `n:` Line count, incremented for each line by the source.
+    pub line_count: Option<u32>,
+    /// `r:` Relative time, in units defined by the source.
+    pub relative_time: Option<u32>,
+    /// `g:` Groups sentences that belong together.
+    pub group: Option<Group>,
+    /// `t:` Text.
+    pub text: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Group {
+    /// The index of this sentence in the group, starting at 1.
+    pub sentence_number: u8,
+    /// Total number of sentences in the group.
+    pub total_in_group: u8,
+    /// Identifies the group.
+    pub id: u32,
+}
+
+impl TagBlock {
+    /// Parses the contents of a TAG block, between the backslashes.
+    pub fn parse(block: &[u8]) -> Result<TagBlock, Nmea0183Error> {
+        let Some(star) = block.iter().rposition(|&x| x == b'*') else {
+            return Err(Nmea0183Error::Incomplete);
+        };
+
+        let check = u8::from_str_radix(str::from_utf8(&block[star + 1..])?, 16)?;
+        if checksum(&block[..star]) != check {
+            return Err(Nmea0183Error::InvalidChecksum);
+        }
+
+        let mut tag_block = TagBlock::default();
+        for field in str::from_utf8(&block[..star])?.split(',') {
+            let Some((code, value)) = field.split_once(':') else {
+                return Err(Nmea0183Error::UnexpectedChar(
+                    field.chars().next().unwrap_or(','),
+                ));
+            };
+
+            match code {
+                "c" => tag_block.time = Some(parse_time(value)?),
+                "s" => tag_block.source = Some(text(value)?),
+                "d" => tag_block.destination = Some(text(value)?),
+                "n" => tag_block.line_count = Some(value.parse()?),
+                "r" => tag_block.relative_time = Some(value.parse()?),
+                "g" => tag_block.group = Some(parse_group(value)?),
+                "t" => tag_block.text = Some(text(value)?),
+                // Ignore codes from newer versions
+                _ => {}
+            }
+        }
+
+        Ok(tag_block)
+    }
+}
+
+/// Splits a line into the contents of its TAG block, if any, and the sentence.
+pub fn split(line: &[u8]) -> Result<(Option<&[u8]>, &[u8]), Nmea0183Error> {
+    if line.first() != Some(&b'\\') {
+        return Ok((None, line));
+    }
+
+    match line[1..].iter().position(|&x| x == b'\\') {
+        Some(end) => Ok((Some(&line[1..end + 1]), &line[end + 2..])),
+        None => Err(Nmea0183Error::Incomplete),
+    }
+}
+
+fn parse_time(value: &str) -> Result<DateTime<Utc>, Nmea0183Error> {
+    let time = value.parse::<i64>()?;
+    let time = match time > MILLISECONDS_THRESHOLD {
+        true => DateTime::from_timestamp_millis(time),
+        false => DateTime::from_timestamp(time, 0),
+    };
+
+    time.ok_or(Nmea0183Error::Incomplete)
+}
+
+/// Parses a group as `sentence-total-id`.
+fn parse_group(value: &str) -> Result<Group, Nmea0183Error> {
+    let mut parts = value.splitn(3, '-');
+    let mut next = || parts.next().ok_or(Nmea0183Error::Incomplete);
+
+    Ok(Group {
+        sentence_number: next()?.parse()?,
+        total_in_group: next()?.parse()?,
+        id: next()?.parse()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(value: &str) -> Option<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(value)
+            .ok()
+            .map(|x| x.with_timezone(&Utc))
+    }
+
+    #[test]
+    fn parses_time_and_source() {
+        let tag_block = TagBlock::parse(b"c:1706781600,s:n2k*03").unwrap();
+        assert_eq!(tag_block.time, time("2024-02-01T10:00:00Z"));
+        assert_eq!(tag_block.source.as_deref(), Some("n2k"));
+    }
+
+    #[test]
+    fn parses_time_in_milliseconds() {
+        let tag_block = TagBlock::parse(b"c:1706781600123,n:42*1F").unwrap();
+        assert_eq!(tag_block.time, time("2024-02-01T10:00:00.123Z"));
+        assert_eq!(tag_block.line_count, Some(42));
+    }
+
+    #[test]
+    fn parses_groups() {
+        let group = TagBlock::parse(b"g:1-2-77,s:r3*7A").unwrap().group.unwrap();
+        assert_eq!(
+            (group.sentence_number, group.total_in_group, group.id),
+            (1, 2, 77)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_checksums() {
+        assert!(matches!(
+            TagBlock::parse(b"c:1706781600,s:n2k*04"),
+            Err(Nmea0183Error::InvalidChecksum)
+        ));
+        assert!(matches!(
+            TagBlock::parse(b"c:1706781600,s:n2k"),
+            Err(Nmea0183Error::Incomplete)
+        ));
+    }
+
+    #[test]
+    fn splits_lines() {
+        let line = b"\\c:1706781600,s:n2k*03\\$IIHDT,274.1,T*22";
+        let (tag_block, sentence) = split(line).unwrap();
+        assert_eq!(tag_block, Some(&b"c:1706781600,s:n2k*03"[..]));
+        assert_eq!(sentence, b"$IIHDT,274.1,T*22");
+
+        assert_eq!(split(b"$IIHDT,274.1,T*22").unwrap().0, None);
+        assert!(split(b"\\c:1706781600*03$IIHDT,274.1,T*22").is_err());
+
+        let msg = crate::Message::parse(line).unwrap();
+        assert_eq!(msg.time(), time("2024-02-01T10:00:00Z"));
+        assert_eq!(msg.source(), Some("n2k"));
+    }
+}
@@ -0,0 +1,34 @@
+/// Who sent a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Talker {
+    /// The two char talker ID, `GP` is commonly used for a GPS.
+    Id([u8; 2]),
+    /// A proprietary sentence, which has a manufacturer instead of a talker.
+    Proprietary,
+}
+
+/// Gets a human readable name for a talker ID.
+/// Returns `None` for unknown talkers.
+pub fn talker_name(id: [u8; 2]) -> Option<&'static str> {
+    Some(match &id {
+        b"GP" => "GPS",
+        b"GL" => "GLONASS",
+        b"GA" => "Galileo",
+        b"GB" | b"BD" => "BeiDou",
+        b"GQ" | b"QZ" => "QZSS",
+        b"GI" => "NavIC",
+        b"GN" => "Combined GNSS",
+        b"AI" => "AIS",
+        b"AG" | b"AP" => "Autopilot",
+        b"EC" => "ECDIS",
+        b"HC" => "Compass",
+        b"HE" | b"HN" => "Gyro",
+        b"II" | b"IN" => "Integrated Instrumentation",
+        b"SD" => "Depth Sounder",
+        b"VW" => "Speed Log",
+        b"WI" => "Weather Instruments",
+        b"YX" => "Transducer",
+        _ => return None,
+    })
+}
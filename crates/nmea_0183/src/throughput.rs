@@ -0,0 +1,125 @@
+//! Throughput of the streaming decoder on recorded data,
+//! shared by the benchmark and the `bench` command of the navigator.
+
+use std::{
+    hint::black_box,
+    io,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::Nmea0183Error,
+    framing::{Frame, FrameReader},
+    ubx::{self, error::UbxError},
+    view::SentenceView,
+    Message,
+};
+
+/// How far each stage of the benchmark goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    /// Splitting the stream into frames.
+    Framing,
+    /// Checking the checksum and address of sentences.
+    Views,
+    /// Decoding sentences and UBX packets.
+    Decode,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Framing, Stage::Views, Stage::Decode];
+}
+
+/// Frames found in one pass over the data.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Counts {
+    pub nmea: u64,
+    pub ubx: u64,
+    /// Frames that failed at the stage, unknown types aren't errors.
+    pub errors: u64,
+}
+
+impl Counts {
+    pub fn frames(&self) -> u64 {
+        self.nmea + self.ubx
+    }
+}
+
+/// The result of decoding the data several times.
+#[derive(Debug, Clone, Copy)]
+pub struct Throughput {
+    pub counts: Counts,
+    pub elapsed: Duration,
+    /// Bytes decoded over every iteration.
+    pub bytes: u64,
+    pub iterations: u32,
+}
+
+impl Throughput {
+    pub fn megabytes_per_second(&self) -> f64 {
+        self.bytes as f64 / 1e6 / self.seconds()
+    }
+
+    pub fn frames_per_second(&self) -> f64 {
+        (self.counts.frames() * self.iterations as u64) as f64 / self.seconds()
+    }
+
+    fn seconds(&self) -> f64 {
+        self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+}
+
+/// Decodes the data the given number of times, up to the given stage.
+pub fn measure(data: &[u8], stage: Stage, iterations: u32) -> io::Result<Throughput> {
+    let start = Instant::now();
+    let mut counts = Counts::default();
+    for _ in 0..iterations {
+        counts = decode(data, stage)?;
+    }
+
+    Ok(Throughput {
+        counts,
+        elapsed: start.elapsed(),
+        bytes: data.len() as u64 * iterations as u64,
+        iterations,
+    })
+}
+
+/// Runs the data through the streaming decoder once, up to the given stage.
+pub fn decode(data: &[u8], stage: Stage) -> io::Result<Counts> {
+    let mut reader = FrameReader::new(data);
+    let mut counts = Counts::default();
+
+    loop {
+        let frame = match reader.next_frame() {
+            Ok(frame) => frame,
+            Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return Ok(counts),
+            Err(err) => return Err(err),
+        };
+
+        // Encapsulated sentences such as AIS aren't supported, so they are missing the `$` prefix
+        let valid = match (frame, stage) {
+            (_, Stage::Framing) | (Frame::Ubx(_), Stage::Views) => true,
+            (Frame::Nmea(line), Stage::Views) => matches!(
+                black_box(SentenceView::parse(line)),
+                Ok(_) | Err(Nmea0183Error::MissingPrefix)
+            ),
+            (Frame::Nmea(line), Stage::Decode) => matches!(
+                black_box(Message::parse(line)),
+                Ok(_) | Err(Nmea0183Error::UnknownType(..) | Nmea0183Error::MissingPrefix)
+            ),
+            (Frame::Ubx(frame), Stage::Decode) => matches!(
+                black_box(ubx::Packet::parse(frame)),
+                Ok(_) | Err(UbxError::UnknownMessage(..))
+            ),
+        };
+
+        match frame {
+            Frame::Nmea(_) => counts.nmea += 1,
+            Frame::Ubx(_) => counts.ubx += 1,
+        }
+        if !valid {
+            counts.errors += 1;
+        }
+    }
+}
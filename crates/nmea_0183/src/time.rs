@@ -0,0 +1,115 @@
+use core::{fmt::Debug, str};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+
+use super::{
+    error::Nmea0183Error,
+    parser::{FromParser, Parser},
+};
+
+/// In UTC.
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Time {
+    pub hour: u8,
+    pub min: u8,
+    pub sec: f32,
+}
+
+/// In UTC.
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Date {
+    pub day: u8,
+    pub month: u8,
+    pub year: u16,
+}
+
+impl Time {
+    pub fn new() -> Self {
+        Self {
+            hour: 0,
+            min: 0,
+            sec: 0.0,
+        }
+    }
+
+    /// Converts to a chrono time, returning `None` if the time is out of range.
+    pub fn to_naive(&self) -> Option<NaiveTime> {
+        let nanos = (self.sec.fract() * 1e9) as u32;
+        NaiveTime::from_hms_nano_opt(self.hour.into(), self.min.into(), self.sec as u32, nanos)
+    }
+}
+
+impl Date {
+    /// Converts to a chrono date, returning `None` if the date is invalid.
+    pub fn to_naive(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.year.into(), self.month.into(), self.day.into())
+    }
+
+    /// Combines this date with a time.
+    pub fn with_time(&self, time: &Time) -> Option<DateTime<Utc>> {
+        let date_time = self.to_naive()?.and_time(time.to_naive()?);
+        Some(date_time.and_utc())
+    }
+}
+
+impl<'a> FromParser<'a> for Time {
+    const NAME: &'static str = "time";
+
+    // Parses a time from "hhmmss.ss";
+    fn parse(parser: &mut Parser<'a>) -> Result<Self, Nmea0183Error> {
+        let hour = parser.next_n(2)?;
+        let min = parser.next_n(2)?;
+        let sec = parser.take_until_or_end(',');
+
+        let hour = str::from_utf8(hour)?.parse::<u8>()?;
+        let min = str::from_utf8(min)?.parse::<u8>()?;
+        let sec = str::from_utf8(sec)?.parse::<f32>()?;
+
+        Ok(Self { hour, min, sec })
+    }
+}
+
+impl<'a> FromParser<'a> for Date {
+    const NAME: &'static str = "date";
+
+    // Parses a date from "ddmmyy", two digit years are assumed to be from 1980 to 2079.
+    fn parse(parser: &mut Parser<'a>) -> Result<Self, Nmea0183Error> {
+        let day = parser.next_n(2)?;
+        let month = parser.next_n(2)?;
+        let year = parser.take_until_or_end(',');
+
+        let day = str::from_utf8(day)?.parse::<u8>()?;
+        let month = str::from_utf8(month)?.parse::<u8>()?;
+        let year = str::from_utf8(year)?.parse::<u16>()?;
+        let year = match year {
+            0..=79 => 2000 + year,
+            80..=99 => 1900 + year,
+            _ => year,
+        };
+
+        Ok(Self { day, month, year })
+    }
+}
+
+impl Debug for Time {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_fmt(format_args!(
+            "{:02}:{:02}:{:02.2}",
+            self.hour, self.min, self.sec
+        ))
+    }
+}
+
+impl Debug for Date {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_fmt(format_args!(
+            "{:04}-{:02}-{:02}",
+            self.year, self.month, self.day
+        ))
+    }
+}
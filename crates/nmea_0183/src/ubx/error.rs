@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum UbxError {
+    #[error("The frame is missing the sync characters")]
+    MissingSync,
+    #[error("The length of the frame or payload was incorrect")]
+    IncorrectLength,
+    #[error("The frame's checksum did not match")]
+    InvalidChecksum,
+    #[error("Unknown message class 0x{0:02X} id 0x{1:02X}")]
+    UnknownMessage(u8, u8),
+}
@@ -0,0 +1,87 @@
+//! The u-blox UBX binary protocol.
+//! Frames are `0xB5 0x62`, a class and id, a little endian payload length, the payload and a two byte checksum.
+
+use self::{
+    error::UbxError,
+    packets::{
+        dilution_of_precision::DilutionOfPrecision, position_velocity_time::PositionVelocityTime,
+        receiver_version::ReceiverVersion, satellite_info::SatelliteInfo,
+    },
+};
+
+pub mod error;
+pub mod packets;
+pub mod payload;
+
+/// Sync characters that start every frame.
+pub const SYNC: [u8; 2] = [0xB5, 0x62];
+/// Sync characters, class, id and length.
+pub const HEADER_LENGTH: usize = 6;
+/// Header and checksum.
+pub const FRAME_OVERHEAD: usize = HEADER_LENGTH + 2;
+/// Payloads longer than this are assumed to be a false sync.
+/// The longest decoded message, NAV-SAT with 255 satellites, is 3068 bytes.
+pub const MAX_PAYLOAD_LENGTH: usize = 4096;
+
+const NAV_DOP: (u8, u8) = (0x01, 0x04);
+const NAV_PVT: (u8, u8) = (0x01, 0x07);
+const NAV_SAT: (u8, u8) = (0x01, 0x35);
+const MON_VER: (u8, u8) = (0x0A, 0x04);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Packet {
+    NavPvt(PositionVelocityTime),
+    NavSat(SatelliteInfo),
+    NavDop(DilutionOfPrecision),
+    MonVer(ReceiverVersion),
+}
+
+impl Packet {
+    /// Parses a complete frame, from the sync characters to the checksum.
+    pub fn parse(frame: &[u8]) -> Result<Packet, UbxError> {
+        if frame.len() < FRAME_OVERHEAD {
+            return Err(UbxError::IncorrectLength);
+        }
+        if frame[..2] != SYNC {
+            return Err(UbxError::MissingSync);
+        }
+        if frame.len() != payload_length(frame) + FRAME_OVERHEAD {
+            return Err(UbxError::IncorrectLength);
+        }
+        if !valid_checksum(frame) {
+            return Err(UbxError::InvalidChecksum);
+        }
+
+        let payload = &frame[HEADER_LENGTH..frame.len() - 2];
+        Ok(match (frame[2], frame[3]) {
+            NAV_PVT => Packet::NavPvt(PositionVelocityTime::parse(payload)?),
+            NAV_SAT => Packet::NavSat(SatelliteInfo::parse(payload)?),
+            NAV_DOP => Packet::NavDop(DilutionOfPrecision::parse(payload)?),
+            MON_VER => Packet::MonVer(ReceiverVersion::parse(payload)?),
+            (class, id) => return Err(UbxError::UnknownMessage(class, id)),
+        })
+    }
+}
+
+/// Gets the payload length from the header of a frame.
+pub fn payload_length(frame: &[u8]) -> usize {
+    u16::from_le_bytes([frame[4], frame[5]]).into()
+}
+
+/// Checks the checksum at the end of a complete frame.
+pub fn valid_checksum(frame: &[u8]) -> bool {
+    let end = frame.len() - 2;
+    checksum(&frame[2..end]) == [frame[end], frame[end + 1]]
+}
+
+/// 8-bit Fletcher checksum over the class, id, length and payload.
+pub fn checksum(data: &[u8]) -> [u8; 2] {
+    let (mut a, mut b) = (0u8, 0u8);
+    for &byte in data {
+        a = a.wrapping_add(byte);
+        b = b.wrapping_add(a);
+    }
+
+    [a, b]
+}
@@ -0,0 +1,41 @@
+use crate::ubx::{error::UbxError, payload::Payload};
+
+/// NAV-DOP, dilution of precision of the navigation solution.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DilutionOfPrecision {
+    /// GPS time of week of the navigation epoch in milliseconds.
+    pub time_of_week: u32,
+    /// Geometric dilution of precision
+    pub gdop: f32,
+    /// Position (3D) dilution of precision
+    pub pdop: f32,
+    /// Time dilution of precision
+    pub tdop: f32,
+    /// Vertical dilution of precision
+    pub vdop: f32,
+    /// Horizontal dilution of precision
+    pub hdop: f32,
+    /// Northing dilution of precision
+    pub ndop: f32,
+    /// Easting dilution of precision
+    pub edop: f32,
+}
+
+impl DilutionOfPrecision {
+    pub fn parse(data: &[u8]) -> Result<DilutionOfPrecision, UbxError> {
+        let payload = Payload::new(data, 18)?;
+        let dop = |offset| f32::from(payload.u16(offset)) * 0.01;
+
+        Ok(Self {
+            time_of_week: payload.u32(0),
+            gdop: dop(4),
+            pdop: dop(6),
+            tdop: dop(8),
+            vdop: dop(10),
+            hdop: dop(12),
+            ndop: dop(14),
+            edop: dop(16),
+        })
+    }
+}
@@ -0,0 +1,4 @@
+pub mod dilution_of_precision;
+pub mod position_velocity_time;
+pub mod receiver_version;
+pub mod satellite_info;
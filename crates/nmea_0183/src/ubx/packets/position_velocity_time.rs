@@ -0,0 +1,106 @@
+use crate::{
+    coordinate::Coordinate,
+    time::{Date, Time},
+    ubx::{error::UbxError, payload::Payload},
+};
+
+/// NAV-PVT, the navigation solution.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PositionVelocityTime {
+    /// GPS time of week of the navigation epoch in milliseconds.
+    pub time_of_week: u32,
+    /// UTC date, if the receiver reports it as valid.
+    pub date: Option<Date>,
+    /// UTC time, if the receiver reports it as valid.
+    pub time: Option<Time>,
+    pub fix: FixType,
+    /// The fix is valid and within the configured accuracy limits.
+    pub fix_ok: bool,
+    /// Number of satellites used in the fix.
+    pub satellites: u8,
+    pub latitude: Coordinate,
+    pub longitude: Coordinate,
+    /// Height above the ellipsoid in meters.
+    pub height: f32,
+    /// Height above mean sea level in meters.
+    pub altitude: f32,
+    /// Horizontal accuracy estimate in meters.
+    pub horizontal_accuracy: f32,
+    /// Vertical accuracy estimate in meters.
+    pub vertical_accuracy: f32,
+    /// Ground speed in meters per second.
+    pub ground_speed: f32,
+    /// Heading of motion in degrees, true.
+    pub heading: f32,
+    /// Position dilution of precision.
+    pub pdop: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FixType {
+    NoFix,
+    DeadReckoning,
+    Fix2D,
+    Fix3D,
+    /// GNSS combined with dead reckoning.
+    GnssDeadReckoning,
+    TimeOnly,
+}
+
+impl PositionVelocityTime {
+    pub fn parse(data: &[u8]) -> Result<PositionVelocityTime, UbxError> {
+        let payload = Payload::new(data, 92)?;
+
+        let valid = payload.u8(11);
+        let date = (valid & 0x01 != 0).then(|| Date {
+            year: payload.u16(4),
+            month: payload.u8(6),
+            day: payload.u8(7),
+        });
+        // The fraction of a second can be negative, rounding the time up
+        let nanos = payload.i32(16);
+        let time = (valid & 0x02 != 0).then(|| Time {
+            hour: payload.u8(8),
+            min: payload.u8(9),
+            sec: (f32::from(payload.u8(10)) + nanos as f32 * 1e-9).max(0.0),
+        });
+
+        let fix = match payload.u8(20) {
+            1 => FixType::DeadReckoning,
+            2 => FixType::Fix2D,
+            3 => FixType::Fix3D,
+            4 => FixType::GnssDeadReckoning,
+            5 => FixType::TimeOnly,
+            _ => FixType::NoFix,
+        };
+
+        Ok(Self {
+            time_of_week: payload.u32(0),
+            date,
+            time,
+            fix,
+            fix_ok: payload.u8(21) & 0x01 != 0,
+            satellites: payload.u8(23),
+            longitude: Coordinate {
+                degree: (f64::from(payload.i32(24)) * 1e-7) as f32,
+            },
+            latitude: Coordinate {
+                degree: (f64::from(payload.i32(28)) * 1e-7) as f32,
+            },
+            height: payload.i32(32) as f32 / 1000.0,
+            altitude: payload.i32(36) as f32 / 1000.0,
+            horizontal_accuracy: payload.u32(40) as f32 / 1000.0,
+            vertical_accuracy: payload.u32(44) as f32 / 1000.0,
+            ground_speed: payload.i32(60) as f32 / 1000.0,
+            heading: payload.i32(64) as f32 * 1e-5,
+            pdop: f32::from(payload.u16(76)) * 0.01,
+        })
+    }
+
+    /// Ground speed in knots.
+    pub fn speed_knots(&self) -> f32 {
+        self.ground_speed * 3600.0 / 1852.0
+    }
+}
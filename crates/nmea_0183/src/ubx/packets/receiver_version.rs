@@ -0,0 +1,46 @@
+use alloc::{boxed::Box, string::String};
+
+use crate::ubx::{error::UbxError, payload::Payload};
+
+/// Length of the software version and of each extension.
+const STRING_LENGTH: usize = 30;
+/// Length of the hardware version.
+const HARDWARE_LENGTH: usize = 10;
+
+/// MON-VER, the receiver software and hardware versions.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReceiverVersion {
+    pub software: String,
+    pub hardware: String,
+    /// Extended version information, such as `PROTVER=18.00` or `MOD=NEO-M8N`.
+    pub extensions: Box<[String]>,
+}
+
+impl ReceiverVersion {
+    pub fn parse(data: &[u8]) -> Result<ReceiverVersion, UbxError> {
+        let payload = Payload::new(data, STRING_LENGTH + HARDWARE_LENGTH)?;
+        let extensions_start = STRING_LENGTH + HARDWARE_LENGTH;
+        if (payload.len() - extensions_start) % STRING_LENGTH != 0 {
+            return Err(UbxError::IncorrectLength);
+        }
+
+        let extensions = (extensions_start..payload.len())
+            .step_by(STRING_LENGTH)
+            .map(|offset| payload.string(offset, STRING_LENGTH))
+            .collect();
+
+        Ok(Self {
+            software: payload.string(0, STRING_LENGTH),
+            hardware: payload.string(STRING_LENGTH, HARDWARE_LENGTH),
+            extensions,
+        })
+    }
+
+    /// Gets the value of a `KEY=value` extension.
+    pub fn extension(&self, key: &str) -> Option<&str> {
+        self.extensions
+            .iter()
+            .find_map(|x| x.strip_prefix(key).and_then(|x| x.strip_prefix('=')))
+    }
+}
@@ -0,0 +1,109 @@
+use alloc::boxed::Box;
+
+use crate::ubx::{error::UbxError, payload::Payload};
+
+/// Length of the header before the satellites.
+const HEADER_LENGTH: usize = 8;
+/// Length of each satellite.
+const SATELLITE_LENGTH: usize = 12;
+
+/// NAV-SAT, the satellites in view.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SatelliteInfo {
+    /// GPS time of week of the navigation epoch in milliseconds.
+    pub time_of_week: u32,
+    pub satellites: Box<[Satellite]>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Satellite {
+    pub constellation: Constellation,
+    /// The id of this satellite within its constellation.
+    pub id: u8,
+    /// Carrier to noise ratio in dBHz, 0 if not tracked.
+    pub cno: u8,
+    /// Elevation in degrees, +/- 90.
+    pub elevation: Option<i8>,
+    /// Azimuth, degrees from true north, 000 to 359.
+    pub azimuth: Option<u16>,
+    /// Signal quality, 0 (no signal) to 7 (code and carrier locked).
+    pub quality: u8,
+    /// The satellite is used in the navigation solution.
+    pub used: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Constellation {
+    Gps,
+    Sbas,
+    Galileo,
+    BeiDou,
+    Imes,
+    Qzss,
+    Glonass,
+    Unknown(u8),
+}
+
+impl SatelliteInfo {
+    pub fn parse(data: &[u8]) -> Result<SatelliteInfo, UbxError> {
+        let payload = Payload::new(data, HEADER_LENGTH)?;
+        let count = usize::from(payload.u8(5));
+        if payload.len() != HEADER_LENGTH + count * SATELLITE_LENGTH {
+            return Err(UbxError::IncorrectLength);
+        }
+
+        let satellites = (0..count)
+            .map(|i| Satellite::parse(&payload, HEADER_LENGTH + i * SATELLITE_LENGTH))
+            .collect();
+
+        Ok(Self {
+            time_of_week: payload.u32(0),
+            satellites,
+        })
+    }
+}
+
+impl Satellite {
+    fn parse(payload: &Payload, offset: usize) -> Self {
+        let constellation = match payload.u8(offset) {
+            0 => Constellation::Gps,
+            1 => Constellation::Sbas,
+            2 => Constellation::Galileo,
+            3 => Constellation::BeiDou,
+            4 => Constellation::Imes,
+            5 => Constellation::Qzss,
+            6 => Constellation::Glonass,
+            x => Constellation::Unknown(x),
+        };
+
+        // The elevation and azimuth are unknown when the elevation is out of range
+        let elevation = Some(payload.i8(offset + 3)).filter(|x| (-90..=90).contains(x));
+        let azimuth = elevation
+            .and(u16::try_from(payload.i16(offset + 4)).ok())
+            .filter(|x| *x < 360);
+        let flags = payload.u32(offset + 8);
+
+        Self {
+            constellation,
+            id: payload.u8(offset + 1),
+            cno: payload.u8(offset + 2),
+            elevation,
+            azimuth,
+            quality: (flags & 0x07) as u8,
+            used: flags & 0x08 != 0,
+        }
+    }
+
+    /// Gets the satellite id as numbered in NMEA 0183 sentences.
+    pub fn nmea_id(&self) -> u8 {
+        match self.constellation {
+            // PRN 120-158 are numbered 33-71
+            Constellation::Sbas if self.id >= 120 => self.id - 87,
+            Constellation::Glonass => self.id.saturating_add(64),
+            _ => self.id,
+        }
+    }
+}
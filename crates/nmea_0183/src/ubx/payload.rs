@@ -0,0 +1,63 @@
+use alloc::string::String;
+
+use super::error::UbxError;
+
+/// Reads little endian fields of a payload at fixed offsets.
+pub struct Payload<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Payload<'a> {
+    /// Checks that the payload is at least `length` bytes long,
+    /// so fields before that offset can be read without failing.
+    pub fn new(data: &'a [u8], length: usize) -> Result<Self, UbxError> {
+        if data.len() < length {
+            return Err(UbxError::IncorrectLength);
+        }
+
+        Ok(Self { data })
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn u8(&self, offset: usize) -> u8 {
+        self.data[offset]
+    }
+
+    pub fn i8(&self, offset: usize) -> i8 {
+        self.data[offset] as i8
+    }
+
+    pub fn u16(&self, offset: usize) -> u16 {
+        u16::from_le_bytes(self.bytes(offset))
+    }
+
+    pub fn i16(&self, offset: usize) -> i16 {
+        i16::from_le_bytes(self.bytes(offset))
+    }
+
+    pub fn u32(&self, offset: usize) -> u32 {
+        u32::from_le_bytes(self.bytes(offset))
+    }
+
+    pub fn i32(&self, offset: usize) -> i32 {
+        i32::from_le_bytes(self.bytes(offset))
+    }
+
+    /// Reads a fixed size, null padded string.
+    pub fn string(&self, offset: usize, length: usize) -> String {
+        let data = &self.data[offset..offset + length];
+        let end = data.iter().position(|&x| x == 0).unwrap_or(length);
+        String::from_utf8_lossy(&data[..end]).into_owned()
+    }
+
+    fn bytes<const N: usize>(&self, offset: usize) -> [u8; N] {
+        self.data[offset..offset + N].try_into().unwrap()
+    }
+}
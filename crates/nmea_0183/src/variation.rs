@@ -0,0 +1,42 @@
+use core::str;
+
+use super::{
+    parser::{FromParser, Parser},
+    Nmea0183Error,
+};
+
+/// `x.x,a`
+/// A magnetic variation or deviation, westerly values are negative.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Variation {
+    pub degree: f32,
+}
+
+impl<'a> FromParser<'a> for Option<Variation> {
+    const NAME: &'static str = "variation";
+    const FIELDS: usize = 2;
+
+    fn parse(parser: &mut Parser<'a>) -> Result<Self, Nmea0183Error> {
+        let value = parser.take_until(',')?;
+        let direction = parser.peek();
+
+        if value.is_empty() {
+            if matches!(direction, Some('E' | 'W')) {
+                parser.skip(1);
+            }
+            return Ok(None);
+        }
+
+        let negative = match parser.next()? {
+            'E' => false,
+            'W' => true,
+            chr => return Err(Nmea0183Error::UnexpectedChar(chr)),
+        };
+
+        let degree = str::from_utf8(value)?.parse::<f32>()?;
+        Ok(Some(Variation {
+            degree: if negative { -degree } else { degree },
+        }))
+    }
+}
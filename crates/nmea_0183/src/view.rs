@@ -0,0 +1,156 @@
+//! Borrowed views of sentences, to look at the address of a sentence without decoding it.
+
+use core::{fmt, str};
+
+use super::{
+    checksum, error::Nmea0183Error, parser::Parser, proprietary::Decoders, tag_block, Message,
+};
+
+/// The address at the start of a sentence, telling who sent it and what it contains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Address {
+    /// A sentence defined by the standard, such as `GPRMC`.
+    Standard {
+        talker: [u8; 2],
+        sentence_type: [u8; 3],
+    },
+    /// A proprietary sentence, such as `PMTK`, the rest of the sentence is defined by the manufacturer.
+    Proprietary { manufacturer: [u8; 3] },
+}
+
+impl Address {
+    /// The talker as shown to users, `P` for proprietary sentences.
+    pub fn talker_id(&self) -> &[u8] {
+        match self {
+            Address::Standard { talker, .. } => talker,
+            Address::Proprietary { .. } => b"P",
+        }
+    }
+
+    /// The sentence type as shown to users, the manufacturer for proprietary sentences.
+    pub fn sentence_type(&self) -> [u8; 3] {
+        match *self {
+            Address::Standard { sentence_type, .. } => sentence_type,
+            Address::Proprietary { manufacturer } => manufacturer,
+        }
+    }
+}
+
+/// Shows the address as it is written in the sentence, such as `GPRMC` or `PMTK`.
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for &byte in self.talker_id().iter().chain(&self.sentence_type()) {
+            write!(f, "{}", byte as char)?;
+        }
+        Ok(())
+    }
+}
+
+/// A sentence with a valid checksum, borrowed from the line it was received in.
+#[derive(Debug, Clone, Copy)]
+pub struct SentenceView<'a> {
+    /// The contents of the TAG block, between the backslashes.
+    pub tag_block: Option<&'a [u8]>,
+    /// The talker and type of the sentence, or the manufacturer of proprietary sentences.
+    pub address: Address,
+    /// The fields after the address, without the checksum.
+    pub data: &'a [u8],
+    /// Where the data starts in the line.
+    pub offset: usize,
+}
+
+impl<'a> SentenceView<'a> {
+    /// Splits a line into its TAG block, address and fields, checking the checksum of the sentence.
+    pub fn parse(line: &'a [u8]) -> Result<Self, Nmea0183Error> {
+        let (tag_block, sentence) = tag_block::split(line)?;
+        let offset = tag_block.map_or(0, |x| x.len() + 2);
+
+        if sentence.first() != Some(&b'$') {
+            return Err(Nmea0183Error::MissingPrefix);
+        }
+
+        if sentence.len() < 9 {
+            return Err(Nmea0183Error::IncorrectLength);
+        }
+
+        let check = &sentence[sentence.len() - 2..];
+        let check = u8::from_str_radix(str::from_utf8(check)?, 16)?;
+        let last = sentence.len() - 3;
+        let calc = checksum(&sentence[0..last]);
+        if calc != check {
+            return Err(Nmea0183Error::InvalidChecksum);
+        }
+
+        // Proprietary sentences have a three char manufacturer instead of a talker and type
+        let (address, start) = match sentence[1] {
+            b'P' => (
+                Address::Proprietary {
+                    manufacturer: [sentence[2], sentence[3], sentence[4]],
+                },
+                5,
+            ),
+            _ => (
+                Address::Standard {
+                    talker: [sentence[1], sentence[2]],
+                    sentence_type: [sentence[3], sentence[4], sentence[5]],
+                },
+                7,
+            ),
+        };
+        let start = start.min(last);
+
+        Ok(Self {
+            tag_block,
+            address,
+            data: &sentence[start..last],
+            offset: offset + start,
+        })
+    }
+
+    pub fn is_proprietary(&self) -> bool {
+        matches!(self.address, Address::Proprietary { .. })
+    }
+
+    /// A parser of the fields, with errors pointing into the whole line.
+    pub fn parser(&self) -> Parser<'a> {
+        Parser::new(self.data)
+            .take_on_parse(',')
+            .with_offset(self.offset)
+    }
+
+    /// Decodes the sentence, failing on the first invalid field.
+    pub fn message(&self) -> Result<Message, Nmea0183Error> {
+        self.message_with(&Decoders::BUILTIN)
+    }
+
+    /// Decodes the sentence, with the given decoders for proprietary sentences.
+    pub fn message_with(&self, decoders: &Decoders) -> Result<Message, Nmea0183Error> {
+        Message::decode(self, &mut self.parser(), decoders)
+    }
+}
+
+/// Reads the address of a line without checking it, so damaged sentences can still be attributed.
+pub fn address(line: &[u8]) -> Option<Address> {
+    let (_, sentence) = tag_block::split(line).ok()?;
+    let (address, chars) = match sentence.get(..6)? {
+        [b'$' | b'!', b'P', a, b, c, _] => (
+            Address::Proprietary {
+                manufacturer: [*a, *b, *c],
+            },
+            &sentence[2..5],
+        ),
+        [b'$' | b'!', t1, t2, a, b, c] => (
+            Address::Standard {
+                talker: [*t1, *t2],
+                sentence_type: [*a, *b, *c],
+            },
+            &sentence[1..6],
+        ),
+        _ => return None,
+    };
+
+    let valid = chars
+        .iter()
+        .all(|x| x.is_ascii_alphanumeric() || *x == b' ');
+    valid.then_some(address)
+}
@@ -0,0 +1,127 @@
+use alloc::{format, string::String};
+use core::fmt::{Display, Write};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+use super::{checksum, coordinate::Coordinate, navigation::Bearing, time::Time};
+
+/// Builds the fields of a sentence, the counterpart of [`Parser`](super::parser::Parser).
+pub struct Writer {
+    fields: String,
+}
+
+impl Writer {
+    pub fn new() -> Self {
+        Self {
+            fields: String::new(),
+        }
+    }
+
+    pub fn field(&mut self, value: impl Display) -> &mut Self {
+        let _ = write!(self.fields, ",{value}");
+        self
+    }
+
+    pub fn empty(&mut self) -> &mut Self {
+        self.fields.push(',');
+        self
+    }
+
+    pub fn optional(&mut self, value: Option<impl Display>) -> &mut Self {
+        match value {
+            Some(value) => self.field(value),
+            None => self.empty(),
+        }
+    }
+
+    /// Writes a float with a fixed number of decimals.
+    pub fn float(&mut self, value: Option<f32>, decimals: usize) -> &mut Self {
+        match value {
+            Some(value) => self.field(format_args!("{value:.decimals$}")),
+            None => self.empty(),
+        }
+    }
+
+    /// Writes text, escaping reserved characters as `^(ascii hex)`.
+    pub fn text(&mut self, value: &str) -> &mut Self {
+        self.fields.push(',');
+        for c in value.chars() {
+            let reserved = matches!(c, '$' | '!' | '*' | ',' | '\\' | '^' | '~');
+            if !c.is_ascii() {
+                self.fields.push('?');
+            } else if reserved || !(c.is_ascii_graphic() || c == ' ') {
+                let _ = write!(self.fields, "^{:02X}", c as u8);
+            } else {
+                self.fields.push(c);
+            }
+        }
+        self
+    }
+
+    /// Writes a latitude as `ddmm.mmm,a`.
+    pub fn latitude(&mut self, value: Option<Coordinate>) -> &mut Self {
+        self.coordinate(value, 2, ['N', 'S'])
+    }
+
+    /// Writes a longitude as `dddmm.mmm,a`.
+    pub fn longitude(&mut self, value: Option<Coordinate>) -> &mut Self {
+        self.coordinate(value, 3, ['E', 'W'])
+    }
+
+    fn coordinate(
+        &mut self,
+        value: Option<Coordinate>,
+        digits: usize,
+        [positive, negative]: [char; 2],
+    ) -> &mut Self {
+        let Some(value) = value else {
+            return self.empty().empty();
+        };
+
+        let hemisphere = if value.degree < 0.0 {
+            negative
+        } else {
+            positive
+        };
+        let value = value.degree.abs();
+        let mut degrees = value.floor();
+        let mut minutes = ((value - degrees) * 60.0 * 1000.0).round() / 1000.0;
+        if minutes >= 60.0 {
+            degrees += 1.0;
+            minutes -= 60.0;
+        }
+
+        self.field(format_args!("{:0digits$}{minutes:06.3}", degrees as u32))
+            .field(hemisphere)
+    }
+
+    /// Writes a time as `hhmmss.ss`.
+    pub fn time(&mut self, value: Option<Time>) -> &mut Self {
+        match value {
+            Some(time) => self.field(format_args!(
+                "{:02}{:02}{:05.2}",
+                time.hour, time.min, time.sec
+            )),
+            None => self.empty(),
+        }
+    }
+
+    /// Writes a bearing as `x.x,a`.
+    pub fn bearing(&mut self, value: Option<Bearing>) -> &mut Self {
+        match value {
+            Some(bearing) => self
+                .float(Some(bearing.degree), 1)
+                .field(bearing.reference.to_char()),
+            None => self.empty().empty(),
+        }
+    }
+
+    /// Completes the sentence, the address is the talker and sentence type (`GPRMB`),
+    /// or a proprietary address (`PMTK220`).
+    pub fn finish(&self, address: &str) -> String {
+        let sentence = format!("${address}{}", self.fields);
+        let checksum = checksum(sentence.as_bytes());
+        format!("{sentence}*{checksum:02X}\r\n")
+    }
+}
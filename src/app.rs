@@ -1,36 +1,85 @@
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::{mpsc::Sender, Arc},
+    time::{Duration, Instant},
+};
 
-use egui::{Align, Color32, Layout, RichText, ScrollArea, SidePanel, TopBottomPanel, Window};
+use egui::{
+    Align, Color32, ComboBox, DragValue, Grid, Layout, RichText, ScrollArea, SidePanel,
+    TopBottomPanel, Window,
+};
 use egui_plot::{Line, Plot};
 use parking_lot::Mutex;
 
-use crate::{
-    args::RunArgs, consts::HISTORY_SAMPLES, log::Log, misc::nullable::Nullable,
-    nmea_0183::stores::Store,
+use nmea_0183::{
+    log::{Log, LogLevel},
+    proprietary::{
+        manufacturer_name,
+        mtk::{AcknowledgeFlag, MtkCommand, OutputRates, OUTPUT_SENTENCES},
+    },
+    stores::{accuracy::Integrity, routes::Navigation, Store, HISTORY_SAMPLES},
+    talker::talker_name,
+    view::Address,
 };
 
+use crate::{args::RunArgs, misc::nullable::Nullable, serial::COMMON_BAUD_RATES, widgets};
+
+/// Time to wait for the receiver to acknowledge a command.
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(3);
+
 pub struct App {
     pub args: RunArgs,
     pub log: Log,
     pub store: Arc<Mutex<Store>>,
+    /// Sentences to send to the device.
+    pub output: Sender<String>,
 
     show_windows: bool,
     show_log: bool,
     show_satellites: bool,
     show_location: bool,
+    show_heading: bool,
+    show_depth: bool,
+    show_wind: bool,
+    show_water: bool,
+    show_sensors: bool,
+    show_route: bool,
+    show_receiver: bool,
+    show_bus: bool,
+
+    fix_rate: u8,
+    baud_rate: u32,
+    sbas: bool,
+    output_sentences: [bool; OUTPUT_SENTENCES.len()],
+    /// The last command sent to the receiver, and when it was sent.
+    command: Option<(MtkCommand, Instant)>,
 }
 
 impl App {
-    pub fn new(args: RunArgs, store: Arc<Mutex<Store>>, log: Log) -> Self {
+    pub fn new(args: RunArgs, store: Arc<Mutex<Store>>, log: Log, output: Sender<String>) -> Self {
         Self {
             args,
             store,
             log,
+            output,
 
             show_windows: false,
             show_log: true,
             show_satellites: true,
             show_location: true,
+            show_heading: false,
+            show_depth: false,
+            show_wind: false,
+            show_water: false,
+            show_sensors: false,
+            show_route: false,
+            show_receiver: false,
+            show_bus: false,
+
+            fix_rate: 1,
+            baud_rate: 9600,
+            sbas: true,
+            output_sentences: [false; OUTPUT_SENTENCES.len()],
+            command: None,
         }
     }
 }
@@ -38,7 +87,7 @@ impl App {
 impl eframe::App for App {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         ctx.request_repaint_after(Duration::from_millis(100));
-        let store = self.store.lock();
+        let mut store = self.store.lock();
 
         TopBottomPanel::top("top_panel").show(ctx, |ui| {
             ui.horizontal(|ui| {
@@ -55,6 +104,15 @@ impl eframe::App for App {
             ui.horizontal(|ui| {
                 ui.label(format!("[ {} ]", self.args.device));
                 ui.label(format!("[ {:?} ]", store.location.fix));
+                let errors = store.bus.errors();
+                if errors > 0 {
+                    ui.label(
+                        RichText::new(format!("[ {errors} BUS ERRORS ]")).color(Color32::YELLOW),
+                    );
+                }
+                if store.depth.is_shallow() {
+                    ui.label(RichText::new("[ SHALLOW WATER ]").color(Color32::RED));
+                }
             });
         });
 
@@ -68,6 +126,14 @@ impl eframe::App for App {
                         ui.toggle_value(&mut self.show_log, "📜 Log");
                         ui.toggle_value(&mut self.show_satellites, "🚀 Satellites");
                         ui.toggle_value(&mut self.show_location, "📍 Position");
+                        ui.toggle_value(&mut self.show_heading, "🧭 Heading");
+                        ui.toggle_value(&mut self.show_depth, "⚓ Depth");
+                        ui.toggle_value(&mut self.show_wind, "🌬 Wind");
+                        ui.toggle_value(&mut self.show_water, "🌊 Water");
+                        ui.toggle_value(&mut self.show_sensors, "🌡 Sensors");
+                        ui.toggle_value(&mut self.show_route, "🗺 Route");
+                        ui.toggle_value(&mut self.show_receiver, "📡 Receiver");
+                        ui.toggle_value(&mut self.show_bus, "🔌 Bus");
                     });
                 });
         }
@@ -125,12 +191,603 @@ impl eframe::App for App {
                 let location = &store.location;
                 ui.label(format!("Latitude: {:?}", location.latitude));
                 ui.label(format!("Longitude: {:?}", location.longitude));
-                ui.label(format!("Time: {:?}", location.time));
+                match location.date_time {
+                    Some(date_time) => {
+                        ui.label(format!(
+                            "Time: {}",
+                            date_time.format("%Y-%m-%d %H:%M:%S%.3f UTC")
+                        ));
+                    }
+                    None => {
+                        ui.label(format!("Time: {:?}", location.time));
+                    }
+                }
+                if let (Some(date_time), Some(offset)) = (location.date_time, location.local_offset)
+                {
+                    ui.label(format!(
+                        "Local time: {}",
+                        date_time
+                            .with_timezone(&offset)
+                            .format("%Y-%m-%d %H:%M:%S %:z")
+                    ));
+                }
+                if let Some(offset) = location.clock_offset {
+                    let seconds = offset.num_milliseconds() as f64 / 1000.0;
+                    let text = RichText::new(format!("System clock offset: {seconds:+.3}s"));
+                    ui.label(if seconds.abs() > 1.0 {
+                        text.color(Color32::YELLOW)
+                    } else {
+                        text
+                    });
+                }
                 ui.label(format!("Status: {:?}", location.status));
                 ui.label(format!("Fix: {:?}", location.fix));
+                for (constellation, mode) in location.constellation_modes.iter() {
+                    ui.label(format!("  {constellation:?}: {mode:?}"));
+                }
+                ui.label(format!(
+                    "Satellites used: {}",
+                    Nullable(location.satellites_used)
+                ));
+                ui.label(format!("Altitude: {}", meters(location.altitude)));
                 ui.label(format!("PDOP: {}", location.pdop));
                 ui.label(format!("HDOP: {}", location.hdop));
                 ui.label(format!("VDOP: {}", location.vdop));
+                ui.label(format!("Course: {}", degrees(location.course)));
+                ui.label(format!("Speed: {}", knots(location.speed)));
+
+                // Show the ship along its heading, or its course if there is no heading sensor
+                ui.add_space(12.0);
+                let (direction, source) = match (store.heading.heading(), location.course) {
+                    (Some(heading), _) => (Some(heading), "Heading"),
+                    (None, Some(course)) => (Some(course), "Course"),
+                    (None, None) => (None, "-"),
+                };
+                ui.horizontal(|ui| {
+                    widgets::own_ship(ui, direction, 64.0);
+                    ui.label(format!("Orientation: {source}"));
+                });
+
+                let accuracy = &store.accuracy;
+                if let Some(errors) = accuracy.errors.get().ok() {
+                    ui.add_space(12.0);
+                    ui.heading("Accuracy (1σ)");
+                    ui.label(format!("Latitude: {}", meters(errors.latitude_error)));
+                    ui.label(format!("Longitude: {}", meters(errors.longitude_error)));
+                    ui.label(format!("Altitude: {}", meters(errors.altitude_error)));
+                    ui.label(format!("Range RMS: {}", meters(errors.rms)));
+                    if let Some((semi_major, semi_minor, orientation)) = accuracy.error_ellipse() {
+                        widgets::error_ellipse(ui, semi_major, semi_minor, orientation, 128.0);
+                    }
+                }
+
+                let integrity = match accuracy.integrity() {
+                    Integrity::Unknown => RichText::new("Unknown"),
+                    Integrity::Ok => RichText::new("OK").color(Color32::GREEN),
+                    Integrity::Fault(satellite) => {
+                        RichText::new(format!("Fault on satellite {satellite}")).color(Color32::RED)
+                    }
+                };
+                ui.horizontal(|ui| {
+                    ui.label("Integrity:");
+                    ui.label(integrity);
+                });
+                if let Some(faults) = accuracy.faults.get().ok() {
+                    ui.label(format!(
+                        "Expected error: {} / {} / {}",
+                        meters(faults.latitude_error),
+                        meters(faults.longitude_error),
+                        meters(faults.altitude_error)
+                    ));
+                    if faults.failed_satellite.is_some() {
+                        ui.label(format!("Bias: {}", meters(faults.bias)));
+                        ui.label(format!(
+                            "Missed detection probability: {}",
+                            Nullable(faults.missed_probability)
+                        ));
+                    }
+                }
+            });
+        }
+
+        if self.show_heading {
+            Window::new("Heading").show(ctx, |ui| {
+                let heading = &store.heading;
+                ui.label(format!("Heading: {}", degrees(heading.heading())));
+                ui.label(format!(
+                    "True: {}",
+                    degrees(heading.true_heading.get().ok().copied())
+                ));
+                ui.label(format!(
+                    "Magnetic: {}",
+                    degrees(heading.magnetic_heading.get().ok().copied())
+                ));
+                ui.label(format!("Deviation: {}", degrees(heading.deviation)));
+                ui.label(format!("Variation: {}", degrees(heading.variation)));
+                ui.label(format!(
+                    "Rate of turn: {}",
+                    Nullable(
+                        heading
+                            .rate_of_turn
+                            .get()
+                            .ok()
+                            .map(|x| format!("{x:.1}°/min"))
+                    )
+                ));
+                ui.label(format!(
+                    "Mode: {}",
+                    Nullable(heading.mode.map(|x| format!("{x:?}")))
+                ));
+
+                ui.add_space(12.0);
+                widgets::own_ship(ui, heading.heading(), 128.0);
+            });
+        }
+
+        if self.show_depth {
+            Window::new("Depth").show(ctx, |ui| {
+                let depth = &mut store.depth;
+
+                let text = RichText::new(format!("{}", meters(depth.depth()))).heading();
+                ui.label(if depth.is_shallow() {
+                    text.color(Color32::RED)
+                } else {
+                    text
+                });
+                ui.label(format!(
+                    "Below transducer: {}",
+                    meters(depth.below_transducer.get().ok().copied())
+                ));
+                ui.label(format!(
+                    "Below surface: {}",
+                    meters(depth.below_surface.get().ok().copied())
+                ));
+                ui.label(format!("Offset: {:.1}m", depth.offset()));
+
+                ui.horizontal(|ui| {
+                    let mut enabled = depth.shallow_threshold.is_some();
+                    ui.checkbox(&mut enabled, "Shallow alarm");
+                    match (enabled, depth.shallow_threshold.as_mut()) {
+                        (true, Some(threshold)) => {
+                            ui.add(DragValue::new(threshold).speed(0.1).suffix("m"));
+                        }
+                        (true, None) => depth.shallow_threshold = Some(2.0),
+                        (false, _) => depth.shallow_threshold = None,
+                    }
+                });
+
+                ui.add_space(12.0);
+                ui.heading("History");
+                let line = Line::new(
+                    depth
+                        .history
+                        .iter()
+                        .enumerate()
+                        .map(|x| [x.0 as f64 - HISTORY_SAMPLES as f64, -*x.1 as f64])
+                        .collect::<Vec<_>>(),
+                );
+                Plot::new("depth_history")
+                    .allow_drag(false)
+                    .view_aspect(2.0)
+                    .show(ui, |plot_ui| plot_ui.line(line));
+            });
+        }
+
+        if self.show_wind {
+            Window::new("Wind").show(ctx, |ui| {
+                let wind = &store.wind;
+                let apparent_angle = wind.apparent_angle.get().ok().copied();
+                let true_angle = wind.true_angle.get().ok().copied();
+
+                widgets::wind_rose(ui, apparent_angle, true_angle, 192.0);
+
+                ui.add_space(12.0);
+                ui.heading(RichText::new("Apparent").color(widgets::APPARENT_WIND_COLOR));
+                ui.label(format!("Angle: {}", degrees(apparent_angle)));
+                ui.label(format!(
+                    "Speed: {}",
+                    knots(wind.apparent_speed.get().ok().copied())
+                ));
+
+                ui.add_space(12.0);
+                ui.heading(RichText::new("True").color(widgets::TRUE_WIND_COLOR));
+                ui.label(format!("Angle: {}", degrees(true_angle)));
+                ui.label(format!(
+                    "Direction: {}",
+                    degrees(wind.true_direction.get().ok().copied())
+                ));
+                ui.label(format!(
+                    "Speed: {}",
+                    knots(wind.true_speed.get().ok().copied())
+                ));
+                ui.label(format!(
+                    "Source: {}",
+                    if wind.true_calculated {
+                        "Calculated"
+                    } else {
+                        "Instrument"
+                    }
+                ));
+
+                ui.add_space(12.0);
+                ui.heading("Last 2 minutes");
+                ui.label(format!("Average speed: {}", knots(wind.average_speed())));
+                ui.label(format!(
+                    "Average direction: {}",
+                    degrees(wind.average_direction())
+                ));
+                ui.label(format!("Gust: {}", knots(wind.gust())));
+            });
+        }
+
+        if self.show_water {
+            Window::new("Water").show(ctx, |ui| {
+                let water = &store.water;
+                ui.label(format!(
+                    "Speed through water: {}",
+                    knots(water.speed.get().ok().copied())
+                ));
+                ui.label(format!(
+                    "Temperature: {}",
+                    Nullable(water.temperature.get().ok().map(|x| format!("{x:.1}°C")))
+                ));
+
+                ui.add_space(12.0);
+                ui.heading("Log");
+                ui.label(format!("Total: {}", nautical_miles(water.total_distance)));
+                ui.label(format!("Trip: {}", nautical_miles(water.trip_distance)));
+                ui.label(format!(
+                    "Total over ground: {}",
+                    nautical_miles(water.total_ground_distance)
+                ));
+                ui.label(format!(
+                    "Trip over ground: {}",
+                    nautical_miles(water.trip_ground_distance)
+                ));
+
+                ui.add_space(12.0);
+                ui.heading("Current");
+                let current = water.current(&store.location, &store.heading);
+                ui.label(format!("Set: {}", degrees(current.map(|x| x.set))));
+                ui.label(format!("Drift: {}", knots(current.map(|x| x.drift))));
+            });
+        }
+
+        if self.show_sensors {
+            Window::new("Sensors").show(ctx, |ui| {
+                let sensors = &store.sensors.sensors;
+                if sensors.is_empty() {
+                    ui.label("No transducer measurements received.");
+                }
+
+                ScrollArea::vertical().show(ui, |ui| {
+                    for (name, sensor) in sensors.iter() {
+                        let unit = sensor.unit_symbol();
+                        let value = sensor.value.get().ok().copied();
+
+                        ui.collapsing(format!("{name} ({:?})", sensor.kind), |ui| {
+                            let label = Nullable(value.map(|x| format!("{x:.1}{unit}")));
+                            widgets::gauge(
+                                ui,
+                                value,
+                                sensor.gauge_range(),
+                                &label.to_string(),
+                                192.0,
+                            );
+
+                            let line = Line::new(
+                                sensor
+                                    .history
+                                    .iter()
+                                    .enumerate()
+                                    .map(|x| [x.0 as f64 - HISTORY_SAMPLES as f64, *x.1 as f64])
+                                    .collect::<Vec<_>>(),
+                            );
+                            Plot::new(format!("sensor_{name}"))
+                                .allow_drag(false)
+                                .view_aspect(2.0)
+                                .show(ui, |plot_ui| plot_ui.line(line));
+                        });
+                    }
+                });
+            });
+        }
+
+        if self.show_route {
+            Window::new("Route").show(ctx, |ui| {
+                let routes = &store.routes;
+                ui.heading("Active route");
+                let mut deactivate = false;
+                match (&routes.active, routes.leg()) {
+                    (Some(active), Some((origin, destination))) => {
+                        ui.horizontal(|ui| {
+                            ui.label(format!("Route: {}", active.route));
+                            deactivate = ui.button("Stop").clicked();
+                        });
+                        ui.label(format!(
+                            "Leg: {} → {destination}",
+                            origin.unwrap_or("Position")
+                        ));
+                        if active.arrived {
+                            ui.label(RichText::new("Arrived").color(Color32::GREEN));
+                        }
+                        navigation(ui, routes.navigation(&store.location).as_ref());
+                    }
+                    _ => {
+                        ui.label("No active route.");
+                    }
+                }
+
+                ui.add_space(12.0);
+                ui.heading("Chartplotter");
+                match routes.received.get().ok() {
+                    Some(received) => {
+                        ui.label(format!(
+                            "Leg: {} → {}",
+                            Nullable(received.origin.as_ref()),
+                            Nullable(received.destination.as_ref())
+                        ));
+                        navigation(ui, Some(received));
+                    }
+                    None => {
+                        ui.label("No navigation received.");
+                    }
+                }
+                if let Some((route, waypoints)) = &routes.working {
+                    ui.label(format!("Working route {route}: {}", waypoints.join(" → ")));
+                }
+
+                ui.add_space(12.0);
+                ui.heading("Routes");
+                if routes.routes.is_empty() {
+                    ui.label("No routes received.");
+                }
+
+                let mut activate = None;
+                ScrollArea::vertical().show(ui, |ui| {
+                    for (name, waypoints) in routes.routes.iter() {
+                        ui.collapsing(format!("{name} ({} waypoints)", waypoints.len()), |ui| {
+                            if ui.button("Navigate").clicked() {
+                                activate = Some(name.clone());
+                            }
+
+                            for waypoint in waypoints.iter() {
+                                let position = routes
+                                    .waypoints
+                                    .get(waypoint)
+                                    .map(|x| format!("{:?} {:?}", x.latitude, x.longitude));
+                                ui.label(format!("{waypoint}: {}", Nullable(position)));
+                            }
+                        });
+                    }
+                });
+
+                if deactivate {
+                    store.routes.deactivate();
+                }
+                if let Some(route) = activate {
+                    store.routes.activate(&route);
+                }
+            });
+        }
+
+        if self.show_receiver {
+            let mut commands = Vec::new();
+            Window::new("Receiver").show(ctx, |ui| {
+                let receiver = &store.receiver;
+                ui.heading("Status");
+                ui.label(format!(
+                    "Firmware: {}",
+                    Nullable(
+                        receiver
+                            .release
+                            .as_ref()
+                            .map(|x| format!("{} ({})", x.release, x.model))
+                            .or_else(|| receiver.version.as_ref().map(|x| format!(
+                                "{} ({})",
+                                x.software,
+                                x.extension("MOD").unwrap_or(&x.hardware)
+                            )))
+                    )
+                ));
+                ui.label(format!(
+                    "Fix rate: {}",
+                    Nullable(
+                        receiver
+                            .fix_interval
+                            .map(|x| format!("{:.1} Hz", 1000.0 / f32::from(x.max(1))))
+                    )
+                ));
+                ui.label(format!(
+                    "SBAS: {}",
+                    Nullable(receiver.sbas_enabled.map(|x| if x { "On" } else { "Off" }))
+                ));
+                ui.label(format!(
+                    "Sentences: {}",
+                    Nullable(receiver.output_rates.as_ref().map(|rates| {
+                        rates
+                            .enabled()
+                            .map(|(name, rate)| format!("{name} ({rate})"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    }))
+                ));
+                if ui.button("Query").clicked() {
+                    commands.extend([
+                        MtkCommand::QueryRelease,
+                        MtkCommand::QueryFixInterval,
+                        MtkCommand::QueryOutputRates,
+                        MtkCommand::QuerySbasEnabled,
+                    ]);
+                }
+
+                ui.add_space(12.0);
+                ui.heading("Configuration");
+                ui.horizontal(|ui| {
+                    ui.label("Fix rate:");
+                    ui.add(
+                        DragValue::new(&mut self.fix_rate)
+                            .clamp_range(1..=10)
+                            .suffix(" Hz"),
+                    );
+                    if ui.button("Set").clicked() {
+                        let interval = 1000 / u16::from(self.fix_rate);
+                        commands.push(MtkCommand::SetFixInterval(interval));
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Baud rate:");
+                    ComboBox::from_id_source("receiver_baud_rate")
+                        .selected_text(self.baud_rate.to_string())
+                        .show_ui(ui, |ui| {
+                            let mut baud_rates = COMMON_BAUD_RATES;
+                            baud_rates.sort();
+                            for baud_rate in baud_rates {
+                                ui.selectable_value(
+                                    &mut self.baud_rate,
+                                    baud_rate,
+                                    baud_rate.to_string(),
+                                );
+                            }
+                        });
+                    if ui.button("Set").clicked() {
+                        commands.push(MtkCommand::SetBaudRate(self.baud_rate));
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut self.sbas, "SBAS");
+                    if ui.button("Set").clicked() {
+                        commands.push(MtkCommand::SetSbasEnabled(self.sbas));
+                    }
+                });
+                ui.collapsing("Output sentences", |ui| {
+                    for (name, enabled) in OUTPUT_SENTENCES.iter().zip(&mut self.output_sentences) {
+                        if !name.is_empty() {
+                            ui.checkbox(enabled, *name);
+                        }
+                    }
+                    ui.horizontal(|ui| {
+                        if ui.button("Set").clicked() {
+                            let rates = OUTPUT_SENTENCES
+                                .iter()
+                                .zip(self.output_sentences)
+                                .filter(|(_, enabled)| *enabled)
+                                .map(|(name, _)| (*name, 1));
+                            if let Ok(rates) = OutputRates::from_rates(rates) {
+                                commands.push(MtkCommand::SetOutputRates(rates));
+                            }
+                        }
+                        if ui.button("Default").clicked() {
+                            commands.push(MtkCommand::ResetOutputRates);
+                        }
+                    });
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Restart:");
+                    if ui.button("Hot").clicked() {
+                        commands.push(MtkCommand::HotRestart);
+                    }
+                    if ui.button("Warm").clicked() {
+                        commands.push(MtkCommand::WarmRestart);
+                    }
+                    if ui.button("Cold").clicked() {
+                        commands.push(MtkCommand::ColdRestart);
+                    }
+                });
+
+                if let Some((command, sent)) = &self.command {
+                    ui.add_space(12.0);
+                    let (status, color) = match command.response_type() {
+                        Some(1) => match receiver.acknowledgement(command.packet_type(), *sent) {
+                            Some(AcknowledgeFlag::Succeeded) => {
+                                ("Succeeded".to_owned(), Color32::GREEN)
+                            }
+                            Some(flag) => (format!("{flag:?}"), Color32::RED),
+                            None if sent.elapsed() < COMMAND_TIMEOUT => {
+                                ("Waiting for acknowledgement".to_owned(), Color32::GRAY)
+                            }
+                            None => ("No acknowledgement".to_owned(), Color32::YELLOW),
+                        },
+                        Some(_) => ("Sent".to_owned(), Color32::GRAY),
+                        None => (
+                            "Sent, restart with the new baud rate".to_owned(),
+                            Color32::YELLOW,
+                        ),
+                    };
+                    ui.label(
+                        RichText::new(format!("PMTK{:03}: {status}", command.packet_type()))
+                            .color(color),
+                    );
+                }
+            });
+
+            for command in commands {
+                let _ = self.output.send(command.encode());
+                self.command = Some((command, Instant::now()));
+            }
+        }
+
+        if self.show_bus {
+            Window::new("Bus").show(ctx, |ui| {
+                let bus = &store.bus;
+                if bus.streams.is_empty() {
+                    ui.label("No sentences received.");
+                }
+                if bus.unreadable > 0 {
+                    ui.label(format!("Unreadable lines: {}", bus.unreadable));
+                }
+
+                ScrollArea::vertical().show(ui, |ui| {
+                    Grid::new("bus").striped(true).show(ui, |ui| {
+                        for header in [
+                            "Talker",
+                            "Type",
+                            "Rate",
+                            "Last seen",
+                            "Checksum",
+                            "Unknown",
+                            "Errors",
+                        ] {
+                            ui.strong(header);
+                        }
+                        ui.end_row();
+
+                        for (address, stream) in bus.streams.iter() {
+                            let color = match (stream.errors() > 0, stream.is_stale()) {
+                                (true, _) => Color32::YELLOW,
+                                (false, true) => Color32::DARK_GRAY,
+                                (false, false) => Color32::PLACEHOLDER,
+                            };
+                            let text = |text: String| RichText::new(text).color(color);
+
+                            let name = match *address {
+                                Address::Standard { talker, .. } => talker_name(talker),
+                                Address::Proprietary { manufacturer } => {
+                                    manufacturer_name(manufacturer)
+                                }
+                            };
+                            ui.label(text(
+                                String::from_utf8_lossy(address.talker_id()).into_owned(),
+                            ))
+                            .on_hover_text(name.unwrap_or("Unknown"));
+                            ui.label(text(
+                                String::from_utf8_lossy(&address.sentence_type()).into_owned(),
+                            ));
+                            ui.label(text(format!("{:.1}/s", stream.rate())));
+                            ui.label(text(format!(
+                                "{}",
+                                Nullable(stream.age().map(|x| format!("{:.1}s", x.as_secs_f32())))
+                            )));
+                            ui.label(text(stream.checksum_failures.to_string()));
+                            ui.label(text(stream.unknown_types.to_string()));
+                            let errors = ui.label(text(stream.parse_errors.to_string()));
+                            if let Some(err) = &stream.last_error {
+                                errors.on_hover_text(err.to_string());
+                            }
+                            ui.end_row();
+                        }
+                    });
+                });
             });
         }
 
@@ -141,9 +798,9 @@ impl eframe::App for App {
                 ScrollArea::vertical().show(ui, |ui| {
                     for (i, entry) in entries.iter().enumerate().rev() {
                         let color = match entry.level {
-                            crate::log::LogLevel::Info => Color32::GRAY,
-                            crate::log::LogLevel::Warning => Color32::YELLOW,
-                            crate::log::LogLevel::Error => Color32::RED,
+                            LogLevel::Info => Color32::GRAY,
+                            LogLevel::Warning => Color32::YELLOW,
+                            LogLevel::Error => Color32::RED,
                         };
 
                         ui.push_id(i, |ui| {
@@ -162,3 +819,42 @@ impl eframe::App for App {
         }
     }
 }
+
+fn navigation(ui: &mut egui::Ui, navigation: Option<&Navigation>) {
+    let cross_track_error = navigation.and_then(|x| x.cross_track_error).map(|x| {
+        let steer = if x > 0.0 { "steer left" } else { "steer right" };
+        format!("{:.2} NM, {steer}", x.abs())
+    });
+    ui.label(format!(
+        "Cross track error: {}",
+        Nullable(cross_track_error)
+    ));
+    ui.label(format!(
+        "Bearing: {}",
+        degrees(navigation.and_then(|x| x.bearing))
+    ));
+    ui.label(format!(
+        "Range: {}",
+        nautical_miles(navigation.and_then(|x| x.range))
+    ));
+    ui.label(format!(
+        "Closing velocity: {}",
+        knots(navigation.and_then(|x| x.closing_velocity))
+    ));
+}
+
+fn degrees(value: Option<f32>) -> Nullable<String> {
+    Nullable(value.map(|x| format!("{x:.1}°")))
+}
+
+fn knots(value: Option<f32>) -> Nullable<String> {
+    Nullable(value.map(|x| format!("{x:.1} kn")))
+}
+
+fn meters(value: Option<f32>) -> Nullable<String> {
+    Nullable(value.map(|x| format!("{x:.1}m")))
+}
+
+fn nautical_miles(value: Option<f32>) -> Nullable<String> {
+    Nullable(value.map(|x| format!("{x:.1} NM")))
+}
@@ -1,4 +1,8 @@
-use clap::Parser;
+use std::{fmt, num::ParseIntError, path::PathBuf, str::FromStr};
+
+use clap::{Parser, ValueEnum};
+
+use crate::input::Source;
 
 #[derive(Parser, Debug)]
 pub struct Args {
@@ -8,43 +12,228 @@ pub struct Args {
 
 #[derive(Parser, Debug)]
 pub enum SubCommand {
-    /// List serial devices available
+    /// List serial devices available, optionally probing them for NMEA 0183 traffic
     Devices(DevicesArgs),
-    /// Prints decoded NMEA 0183 messages from a serial device
+    /// Prints decoded NMEA 0183 and UBX messages from a serial device
     Debug(DebugArgs),
     /// Runs the main desktop application
     Run(RunArgs),
+    /// Compares GPS time to the system clock, optionally feeding it to a time daemon
+    Clock(ClockArgs),
+    /// Configures a MediaTek (PMTK) receiver
+    Receiver(ReceiverArgs),
+    /// Measures the decoding throughput on a recorded log
+    Bench(BenchArgs),
+    /// Prints the descriptive record of an S-57 chart file
+    #[cfg(feature = "s57")]
+    Chart(ChartArgs),
 }
 
 #[derive(Parser, Debug)]
-pub struct DevicesArgs {}
+pub struct DevicesArgs {
+    /// Listen on each port to detect its baud rate, talkers and sentence types
+    #[clap(short, long)]
+    pub probe: bool,
+    /// Time to listen at each baud rate when probing (in seconds)
+    #[clap(short, long, default_value = "1.5")]
+    pub sample_time: f32,
+}
 
 #[derive(Parser, Debug)]
 pub struct DebugArgs {
-    /// Serial device to read from
-    pub device: String,
-    /// Baud rate to use
+    /// Serial device, log file, `-` for stdin, `tcp://host:port` or `udp://host:port` to read from
+    pub input: Source,
+    /// Baud rate to use, or `auto` to detect it
     #[clap(short, long, default_value = "4800")]
-    pub baud_rate: u32,
+    pub baud_rate: BaudRate,
     /// Time to wait for a message before timing out (in seconds)
     #[clap(short, long, default_value = "2")]
     pub timeout: f32,
-    /// Print raw NMEA 0183 messages
+    /// Print raw NMEA 0183 messages and UBX frames
     #[clap(short, long)]
     pub raw: bool,
     /// Ignore message parsing errors
     #[clap(short, long)]
     pub ignore_errors: bool,
+    /// Only show sentences from these talkers, `P` for proprietary sentences.
+    /// UBX frames are hidden when filtering.
+    #[clap(long = "talker", value_delimiter = ',')]
+    pub talkers: Vec<String>,
+    /// Only show these sentence types, or manufacturers of proprietary sentences
+    #[clap(long = "sentence", value_delimiter = ',')]
+    pub sentences: Vec<String>,
+    /// Output format
+    #[clap(short, long, value_enum, default_value = "debug")]
+    pub format: OutputFormat,
+    /// Only print statistics of the received sentences, on Ctrl-C or at the end of the input
+    #[clap(short, long)]
+    pub summary: bool,
 }
 
 #[derive(Parser, Debug, Clone)]
 pub struct RunArgs {
+    /// Serial device to read from, or a Signal K server to read instead, `ws://host:port`
+    pub device: String,
+    /// Baud rate to use, or `auto` to detect it
+    #[clap(short, long, default_value = "4800")]
+    pub baud_rate: BaudRate,
+    /// Time to wait for a message before timing out (in seconds)
+    #[clap(short, long, default_value = "2")]
+    pub timeout: f32,
+    /// Depth transducer offset (in meters).
+    /// Positive is the distance to the waterline, negative is the distance to the keel.
+    #[clap(long, allow_hyphen_values = true)]
+    pub depth_offset: Option<f32>,
+    /// Depth to raise the shallow water alarm below (in meters)
+    #[clap(long)]
+    pub shallow_alarm: Option<f32>,
+    /// Send the active route and steering sentences (RMB, APB, XTE, BOD, BWC, WPL, RTE) to the device
+    #[clap(long)]
+    pub route_output: bool,
+    /// Serve the decoded data as Signal K deltas and the full model on this address, such as `0.0.0.0:3000`
+    #[clap(long)]
+    pub signal_k: Option<String>,
+}
+
+#[derive(Parser, Debug)]
+pub struct ClockArgs {
     /// Serial device to read from
     pub device: String,
-    /// Baud rate to use
+    /// Baud rate to use, or `auto` to detect it
     #[clap(short, long, default_value = "4800")]
-    pub baud_rate: u32,
+    pub baud_rate: BaudRate,
     /// Time to wait for a message before timing out (in seconds)
     #[clap(short, long, default_value = "2")]
     pub timeout: f32,
+    /// Minimum fix (from GSA sentences) needed to use the GPS time
+    #[clap(short, long, value_enum, default_value = "none")]
+    pub min_fix: MinFix,
+    /// Write samples to the ntpd / chrony shared memory segment with this unit number
+    #[clap(short, long)]
+    pub shm: Option<u8>,
+    /// Write the latest offset (in seconds) to this file
+    #[clap(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Parser, Debug)]
+pub struct ReceiverArgs {
+    /// Serial device of the receiver
+    pub device: String,
+    /// Baud rate to use, or `auto` to detect it
+    #[clap(short, long, default_value = "auto")]
+    pub baud_rate: BaudRate,
+    /// Time to wait for the receiver to answer (in seconds)
+    #[clap(short, long, default_value = "3")]
+    pub timeout: f32,
+    #[clap(subcommand)]
+    pub command: ReceiverCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum ReceiverCommand {
+    /// Queries the firmware release, fix rate, output sentences and SBAS state
+    Info,
+    /// Sets the fix update rate (in Hz)
+    Rate {
+        #[clap(value_parser = clap::value_parser!(u8).range(1..=10))]
+        hz: u8,
+    },
+    /// Sets the baud rate of the receiver
+    Baud { baud_rate: u32 },
+    /// Sets the output sentences as `NAME` or `NAME=N` (every N fixes), or `default`
+    Sentences {
+        #[clap(required = true)]
+        sentences: Vec<String>,
+    },
+    /// Restarts the receiver
+    Restart {
+        #[clap(value_enum)]
+        mode: RestartMode,
+    },
+    /// Enables or disables SBAS satellites
+    Sbas {
+        #[clap(value_enum)]
+        state: Switch,
+    },
+}
+
+#[derive(Parser, Debug)]
+pub struct BenchArgs {
+    /// Log of the raw bytes received from a device, NMEA 0183 sentences and UBX frames
+    pub log: PathBuf,
+    /// Number of times to decode the log for each stage
+    #[clap(short, long, default_value = "10")]
+    pub iterations: u32,
+}
+
+#[cfg(feature = "s57")]
+#[derive(Parser, Debug)]
+pub struct ChartArgs {
+    /// S-57 chart file, such as `US5MA22M.000`
+    pub path: PathBuf,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum RestartMode {
+    /// Keep all data
+    Hot,
+    /// Discard the ephemeris
+    Warm,
+    /// Discard the time, position, almanac and ephemeris
+    Cold,
+    /// Cold restart and reset the configuration to the factory defaults
+    Full,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum Switch {
+    On,
+    Off,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Rust debug formatting of the decoded messages
+    Debug,
+    /// One JSON object per line
+    Json,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum MinFix {
+    /// Only a valid RMC status is needed
+    None,
+    #[value(name = "2d")]
+    Fix2D,
+    #[value(name = "3d")]
+    Fix3D,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum BaudRate {
+    /// Try common baud rates and use the one with the most valid sentences.
+    Auto,
+    Fixed(u32),
+}
+
+impl FromStr for BaudRate {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("auto") {
+            return Ok(Self::Auto);
+        }
+
+        Ok(Self::Fixed(s.parse()?))
+    }
+}
+
+impl fmt::Display for BaudRate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BaudRate::Auto => f.write_str("auto"),
+            BaudRate::Fixed(baud_rate) => f.write_fmt(format_args!("{}", baud_rate)),
+        }
+    }
 }
@@ -0,0 +1,38 @@
+use std::fs;
+
+use anyhow::{Context, Result};
+
+use nmea_0183::throughput::{self, Stage};
+
+use crate::args::BenchArgs;
+
+pub fn run(args: &BenchArgs) -> Result<()> {
+    let data = fs::read(&args.log).context("Failed to read log")?;
+    println!(
+        "[*] {} ({:.1} MB), {} iterations",
+        args.log.display(),
+        data.len() as f64 / 1e6,
+        args.iterations
+    );
+
+    for stage in Stage::ALL {
+        let throughput = throughput::measure(&data, stage, args.iterations)?;
+        println!(
+            " {} {:?}: {:.1} MB/s, {:.0} frames/s",
+            if stage == Stage::Decode { "└" } else { "├" },
+            stage,
+            throughput.megabytes_per_second(),
+            throughput.frames_per_second()
+        );
+
+        if stage == Stage::Decode {
+            let counts = throughput.counts;
+            println!(
+                "   {} sentences, {} UBX frames, {} errors per iteration",
+                counts.nmea, counts.ubx, counts.errors
+            );
+        }
+    }
+
+    Ok(())
+}
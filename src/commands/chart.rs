@@ -0,0 +1,16 @@
+use std::fs::File;
+
+use anyhow::{Context, Result};
+use iso_8211::{data_descriptive_record::DataDescriptiveRecord, parser::Parser};
+
+use crate::args::ChartArgs;
+
+pub fn run(args: &ChartArgs) -> Result<()> {
+    let file = File::open(&args.path).context("Failed to open chart")?;
+    let mut parser = Parser::new(file);
+    let record = DataDescriptiveRecord::parse(&mut parser)
+        .context("Failed to parse the descriptive record")?;
+    println!("{record:#?}");
+
+    Ok(())
+}
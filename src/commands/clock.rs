@@ -0,0 +1,131 @@
+use std::{
+    fs,
+    io::{BufRead, BufReader, ErrorKind},
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+
+use nmea_0183::{
+    error::Nmea0183Error,
+    packets::{active_satellites::Fix, geographic_position::Status},
+    stores::location::Location,
+    Sentence,
+};
+
+use crate::{
+    args::{ClockArgs, MinFix},
+    serial,
+};
+
+/// Precision of the samples (log2 seconds), about half a second.
+/// NMEA 0183 sentences are sent some time after the second they describe, and timing varies by receiver.
+const PRECISION: i32 = -1;
+
+pub fn run(args: &ClockArgs) -> Result<()> {
+    let port = serial::open(
+        &args.device,
+        args.baud_rate,
+        Duration::from_secs_f32(args.timeout),
+    )?;
+    let mut reader = BufReader::new(port);
+
+    #[cfg(unix)]
+    let mut shm = match args.shm {
+        Some(unit) => Some(
+            crate::misc::ntp_shm::NtpShm::open(unit)
+                .context("Failed to open the NTP shared memory segment")?,
+        ),
+        None => None,
+    };
+    #[cfg(not(unix))]
+    if args.shm.is_some() {
+        anyhow::bail!("Shared memory output is only supported on unix systems");
+    }
+
+    let mut location = Location::new();
+    let mut waiting = true;
+    println!("[*] Waiting for a valid fix");
+
+    let mut line = Vec::new();
+    loop {
+        match reader.read_until(b'\n', &mut line) {
+            Ok(_) => {}
+            // Nothing was received within the timeout, keep what was read of the line and wait
+            Err(err) if matches!(err.kind(), ErrorKind::TimedOut | ErrorKind::WouldBlock) => {
+                continue
+            }
+            Err(err) => {
+                return Err(err).with_context(|| format!("Failed to read from {}", args.device))
+            }
+        }
+        let received = Utc::now();
+        let buffer = std::mem::take(&mut line);
+        let line = serial::trim_line(&buffer);
+        if line.is_empty() {
+            continue;
+        }
+
+        let msg = match nmea_0183::Message::parse(line) {
+            Ok(msg) => msg,
+            Err(Nmea0183Error::UnknownType(..)) => continue,
+            Err(err) => {
+                eprintln!("[-] NMEA Error: {:?}", err);
+                continue;
+            }
+        };
+
+        // Offsets are against the system clock, so a TAG block time from another clock isn't used
+        let sentence = msg.message;
+
+        // Only sentences with a date give a full time
+        let dated = matches!(sentence, Sentence::Rmc(_) | Sentence::Zda(_));
+        location.handle(&sentence, received);
+
+        let Some(gps_time) = location.date_time.filter(|_| dated) else {
+            continue;
+        };
+
+        if !matches!(location.status, Status::DataValid) || !fix_allowed(args.min_fix, location.fix)
+        {
+            if !waiting {
+                println!("[*] Lost fix, waiting for a valid fix");
+                waiting = true;
+            }
+            continue;
+        }
+        waiting = false;
+
+        let offset = offset_seconds(gps_time, received);
+        println!(
+            "[*] GPS: {} System: {} Offset: {offset:+.3}s",
+            gps_time.format("%Y-%m-%d %H:%M:%S%.3f"),
+            received.format("%H:%M:%S%.3f"),
+        );
+
+        #[cfg(unix)]
+        if let Some(shm) = &mut shm {
+            shm.write(gps_time, received, PRECISION);
+        }
+
+        if let Some(output) = &args.output {
+            fs::write(output, format!("{offset:.6}\n"))
+                .with_context(|| format!("Failed to write to {}", output.display()))?;
+        }
+    }
+}
+
+/// System time minus GPS time, in seconds.
+fn offset_seconds(gps_time: DateTime<Utc>, received: DateTime<Utc>) -> f64 {
+    let offset = received - gps_time;
+    offset.num_microseconds().unwrap_or(i64::MAX) as f64 / 1e6
+}
+
+fn fix_allowed(min_fix: MinFix, fix: Fix) -> bool {
+    match min_fix {
+        MinFix::None => true,
+        MinFix::Fix2D => matches!(fix, Fix::Fix2D | Fix::Fix3D),
+        MinFix::Fix3D => matches!(fix, Fix::Fix3D),
+    }
+}
@@ -1,34 +1,317 @@
 use std::{
-    io::{BufRead, BufReader},
-    time::Duration,
+    io::ErrorKind,
+    process,
+    sync::Arc,
+    time::{Duration, Instant},
 };
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
+use parking_lot::Mutex;
+use serde_json::{json, Value};
 
-use crate::{args::DebugArgs, nmea_0183};
+use nmea_0183::{
+    error::{FieldError, Nmea0183Error},
+    framing::{Frame, FrameReader},
+    stores::bus::Bus,
+    ubx,
+    view::{self, SentenceView},
+    Message,
+};
+
+use crate::{
+    args::{DebugArgs, OutputFormat},
+    input,
+};
+
+/// Statistics of everything received, for the summary.
+struct Stats {
+    bus: Bus,
+    ubx_frames: u32,
+    ubx_errors: u32,
+    started: Instant,
+}
 
 pub fn run(args: &DebugArgs) -> Result<()> {
-    let port = serialport::new(&args.device, args.baud_rate)
-        .timeout(Duration::from_secs_f32(args.timeout))
-        .open()
-        .context("Failed to open port")?;
+    let input = input::open(
+        &args.input,
+        args.baud_rate,
+        Duration::from_secs_f32(args.timeout),
+    )?;
+
+    let stats = Arc::new(Mutex::new(Stats {
+        bus: Bus::new(),
+        ubx_frames: 0,
+        ubx_errors: 0,
+        started: Instant::now(),
+    }));
+    if args.summary {
+        let stats = stats.clone();
+        let format = args.format;
+        ctrlc::set_handler(move || {
+            summary(&stats.lock(), format);
+            process::exit(0);
+        })?;
+    }
 
-    let mut reader = BufReader::new(port);
+    let mut reader = FrameReader::new(input);
+    let result = loop {
+        let frame = match reader.next_frame() {
+            Ok(frame) => frame,
+            // The end of a log file or stdin
+            Err(err) if err.kind() == ErrorKind::UnexpectedEof => break Ok(()),
+            // Serial devices and network streams may be quiet for longer than the timeout
+            Err(err) if matches!(err.kind(), ErrorKind::TimedOut | ErrorKind::WouldBlock) => {
+                continue
+            }
+            Err(err) => break Err(err),
+        };
 
-    loop {
-        let mut line = Vec::new();
-        reader.read_until(b'\n', &mut line)?;
-        let end = line.len() - 2;
+        match frame {
+            Frame::Nmea(line) => {
+                if !matches_filters(args, line) {
+                    continue;
+                }
 
-        if args.raw {
-            println!("{:?}", String::from_utf8_lossy(&line[..end]));
-        }
+                let (msg, diagnostics) = nmea_0183::Message::parse_lenient(line);
+                stats.lock().bus.handle(line, &msg);
+                if args.summary || (args.ignore_errors && msg.is_err()) {
+                    continue;
+                }
+
+                match args.format {
+                    OutputFormat::Debug => print_nmea(args, line, &msg, &diagnostics),
+                    OutputFormat::Json => println!("{}", nmea_json(line, &msg, &diagnostics)),
+                }
+            }
+            Frame::Ubx(frame) => {
+                if !args.talkers.is_empty() || !args.sentences.is_empty() {
+                    continue;
+                }
 
-        let msg = nmea_0183::Message::parse(&line[..end]);
-        match msg {
-            Ok(msg) => println!("{:?}", msg),
-            Err(err) if !args.ignore_errors => eprintln!("Error: {:?}", err),
-            _ => {}
+                let packet = ubx::Packet::parse(frame);
+                {
+                    let mut stats = stats.lock();
+                    stats.ubx_frames += 1;
+                    if packet.is_err() {
+                        stats.ubx_errors += 1;
+                    }
+                }
+                if args.summary || (args.ignore_errors && packet.is_err()) {
+                    continue;
+                }
+
+                match args.format {
+                    OutputFormat::Debug => {
+                        if args.raw {
+                            println!("{:02X?}", frame);
+                        }
+
+                        match packet {
+                            Ok(packet) => println!("{:?}", packet),
+                            Err(err) => eprintln!("Error: {:?}", err),
+                        }
+                    }
+                    OutputFormat::Json => {
+                        let error = packet.err().map(|x| x.to_string());
+                        println!("{}", ubx_json(frame, error))
+                    }
+                }
+            }
         }
+    };
+
+    // The summary is printed before any error, so what was received isn't lost
+    if args.summary {
+        summary(&stats.lock(), args.format);
+    }
+
+    result.with_context(|| format!("Failed to read from {}", args.input))
+}
+
+/// Checks the talker and sentence type of a line against the filters, without decoding it.
+fn matches_filters(args: &DebugArgs, line: &[u8]) -> bool {
+    if args.talkers.is_empty() && args.sentences.is_empty() {
+        return true;
+    }
+
+    let Some(address) = view::address(line) else {
+        return false;
+    };
+    let matches = |filters: &[String], value: &[u8]| {
+        filters.is_empty()
+            || filters
+                .iter()
+                .any(|x| x.as_bytes().eq_ignore_ascii_case(value))
+    };
+
+    matches(&args.talkers, address.talker_id())
+        && matches(&args.sentences, &address.sentence_type())
+}
+
+fn print_nmea(
+    args: &DebugArgs,
+    line: &[u8],
+    msg: &Result<Message, Nmea0183Error>,
+    diagnostics: &[FieldError],
+) {
+    if args.raw {
+        println!("{:?}", String::from_utf8_lossy(line));
+    }
+
+    match msg {
+        Ok(msg) => println!("{:?}", msg),
+        // Shown with the other invalid fields
+        Err(Nmea0183Error::InvalidField(_)) => {}
+        Err(err) => eprintln!("Error: {:?}", err),
+    }
+
+    if !args.ignore_errors && !diagnostics.is_empty() {
+        eprintln!("{}", highlight(line, diagnostics));
+    }
+}
+
+/// Shows the line with each invalid field underlined.
+fn highlight(line: &[u8], diagnostics: &[FieldError]) -> String {
+    let mut out = format!(" |  {}", String::from_utf8_lossy(line));
+    for diagnostic in diagnostics {
+        out.push_str(&format!(
+            "\n |  {}{} field {}: expected {}, {}",
+            " ".repeat(diagnostic.offset),
+            "^".repeat(diagnostic.length.max(1)),
+            diagnostic.field,
+            diagnostic.expected,
+            diagnostic.error,
+        ));
+    }
+
+    out
+}
+
+fn timestamp(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// A sentence as a JSON object.
+/// Field names are stable, so the output can be processed by other tools.
+fn nmea_json(
+    line: &[u8],
+    msg: &Result<Message, Nmea0183Error>,
+    diagnostics: &[FieldError],
+) -> Value {
+    let address = view::address(line);
+    let view = SentenceView::parse(line).ok();
+    let tag_block = msg.as_ref().ok().and_then(|x| x.tag_block.as_ref());
+    // The TAG block time is when the sentence was received, the system clock is only a fallback
+    let received = msg.as_ref().ok().and_then(Message::time);
+
+    json!({
+        "kind": "nmea",
+        "received": timestamp(received.unwrap_or_else(Utc::now)),
+        "talker": address.map(|x| String::from_utf8_lossy(x.talker_id()).into_owned()),
+        "sentence_type": address.map(|x| String::from_utf8_lossy(&x.sentence_type()).into_owned()),
+        "raw": String::from_utf8_lossy(line),
+        "fields": view.map(|x| {
+            x.data
+                .split(|&x| x == b',')
+                .map(|x| String::from_utf8_lossy(x).into_owned())
+                .collect::<Vec<_>>()
+        }),
+        "tag_block": tag_block.map(|x| json!({
+            "time": x.time.map(timestamp),
+            "source": x.source,
+            "destination": x.destination,
+            "line_count": x.line_count,
+            "relative_time": x.relative_time,
+            "text": x.text,
+        })),
+        "message": message_json(msg),
+        "valid": msg.is_ok() && diagnostics.is_empty(),
+        "error": msg.as_ref().err().map(|x| x.to_string()),
+        "diagnostics": diagnostics.iter().map(|x| json!({
+            "field": x.field,
+            "offset": x.offset,
+            "length": x.length,
+            "expected": x.expected,
+            "raw": x.raw,
+            "error": x.error.to_string(),
+        })).collect::<Vec<_>>(),
+    })
+}
+
+/// The decoded sentence, named by its type.
+#[cfg(feature = "serde")]
+fn message_json(msg: &Result<Message, Nmea0183Error>) -> Value {
+    msg.as_ref()
+        .ok()
+        .and_then(|x| serde_json::to_value(&x.message).ok())
+        .unwrap_or_default()
+}
+
+/// Decoded sentences are only included with the `serde` feature.
+#[cfg(not(feature = "serde"))]
+fn message_json(_msg: &Result<Message, Nmea0183Error>) -> Value {
+    Value::Null
+}
+
+/// A UBX frame as a JSON object.
+fn ubx_json(frame: &[u8], error: Option<String>) -> Value {
+    json!({
+        "kind": "ubx",
+        "received": timestamp(Utc::now()),
+        "class": frame[2],
+        "id": frame[3],
+        "raw": frame.iter().map(|x| format!("{x:02X}")).collect::<String>(),
+        "valid": error.is_none(),
+        "error": error,
+    })
+}
+
+/// Prints the statistics of each talker and sentence type.
+fn summary(stats: &Stats, format: OutputFormat) {
+    let elapsed = stats.started.elapsed().as_secs_f32();
+    let rate = |count: u32| count as f32 / elapsed.max(f32::EPSILON);
+
+    if format == OutputFormat::Json {
+        let streams = stats.bus.streams.iter().map(|(address, stream)| {
+            json!({
+                "talker": String::from_utf8_lossy(address.talker_id()),
+                "sentence_type": String::from_utf8_lossy(&address.sentence_type()),
+                "received": stream.received,
+                "rate": rate(stream.received),
+                "checksum_failures": stream.checksum_failures,
+                "unknown_types": stream.unknown_types,
+                "parse_errors": stream.parse_errors,
+            })
+        });
+        let summary = json!({
+            "kind": "summary",
+            "elapsed": elapsed,
+            "streams": streams.collect::<Vec<_>>(),
+            "unreadable": stats.bus.unreadable,
+            "ubx_frames": stats.ubx_frames,
+            "ubx_errors": stats.ubx_errors,
+        });
+        println!("{summary}");
+        return;
+    }
+
+    println!("[*] Summary after {elapsed:.1}s");
+    for (address, stream) in stats.bus.streams.iter() {
+        println!(
+            " ├ {address}: {} ({:.1}/s), {} checksum failures, {} unknown, {} errors",
+            stream.received,
+            rate(stream.received),
+            stream.checksum_failures,
+            stream.unknown_types,
+            stream.parse_errors,
+        );
     }
+    println!(" ├ Unreadable lines: {}", stats.bus.unreadable);
+    println!(
+        " └ UBX: {} ({:.1}/s), {} errors",
+        stats.ubx_frames,
+        rate(stats.ubx_frames),
+        stats.ubx_errors
+    );
 }
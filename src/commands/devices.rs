@@ -1,9 +1,13 @@
+use std::time::Duration;
+
 use anyhow::{Context, Result};
 use serialport::SerialPortType;
 
-use crate::args::DevicesArgs;
+use nmea_0183::{proprietary::manufacturer_name, talker::talker_name};
+
+use crate::{args::DevicesArgs, serial};
 
-pub fn run(_args: &DevicesArgs) -> Result<()> {
+pub fn run(args: &DevicesArgs) -> Result<()> {
     let ports = serialport::available_ports().context("Error getting serial ports.")?;
 
     if ports.is_empty() {
@@ -25,18 +29,71 @@ pub fn run(_args: &DevicesArgs) -> Result<()> {
             port.port_name
         );
 
+        let mut details = Vec::new();
         if let SerialPortType::UsbPort(usb) = &port.port_type {
             if let Some(product) = usb.product.as_ref() {
-                println!("   ├─ Product: {}", product);
+                details.push(format!("Product: {}", product));
             }
             if let Some(manufacturer) = usb.manufacturer.as_ref() {
-                println!("   ├─ Manufacturer: {}", manufacturer);
+                details.push(format!("Manufacturer: {}", manufacturer));
             }
             if let Some(serial_number) = usb.serial_number.as_ref() {
-                println!("   ├─ Serial Number: {}", serial_number);
+                details.push(format!("Serial Number: {}", serial_number));
             }
-            println!("   ├─ Vendor ID: 0x{:04x}", usb.vid);
-            println!("   └─ Product ID: 0x{:04x}", usb.pid);
+            details.push(format!("Vendor ID: 0x{:04x}", usb.vid));
+            details.push(format!("Product ID: 0x{:04x}", usb.pid));
+        }
+
+        if args.probe {
+            let sample_time = Duration::from_secs_f32(args.sample_time);
+            match serial::detect_baud_rate(&port.port_name, sample_time) {
+                Ok(Some(best)) => {
+                    details.push(format!(
+                        "Baud Rate: {} ({:.1} sentences/s)",
+                        best.baud_rate,
+                        best.score()
+                    ));
+                    let talkers = best
+                        .talkers
+                        .iter()
+                        .map(|&id| {
+                            let talker = String::from_utf8_lossy(&id);
+                            match talker_name(id) {
+                                Some(name) => format!("{talker} ({name})"),
+                                None => talker.into_owned(),
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    details.push(format!("Talkers: {}", talkers.join(", ")));
+                    let sentence_types = best
+                        .sentence_types
+                        .iter()
+                        .map(|id| String::from_utf8_lossy(id).into_owned())
+                        .collect::<Vec<_>>();
+                    details.push(format!("Sentences: {}", sentence_types.join(", ")));
+                    if !best.manufacturers.is_empty() {
+                        let manufacturers = best
+                            .manufacturers
+                            .iter()
+                            .map(|&id| {
+                                let manufacturer = String::from_utf8_lossy(&id);
+                                match manufacturer_name(id) {
+                                    Some(name) => format!("{manufacturer} ({name})"),
+                                    None => manufacturer.into_owned(),
+                                }
+                            })
+                            .collect::<Vec<_>>();
+                        details.push(format!("Proprietary: {}", manufacturers.join(", ")));
+                    }
+                }
+                Ok(None) => details.push("NMEA 0183: Not detected".to_owned()),
+                Err(err) => details.push(format!("NMEA 0183: {err}")),
+            }
+        }
+
+        for (j, detail) in details.iter().enumerate() {
+            let branch = if j + 1 == details.len() { "└" } else { "├" };
+            println!("   {branch}─ {detail}");
         }
     }
 
@@ -1,3 +1,8 @@
+pub mod bench;
+#[cfg(feature = "s57")]
+pub mod chart;
+pub mod clock;
 pub mod debug;
 pub mod devices;
+pub mod receiver;
 pub mod run;
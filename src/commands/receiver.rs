@@ -0,0 +1,182 @@
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context, Result};
+
+use nmea_0183::{
+    proprietary::{
+        mtk::{MtkCommand, MtkSentence, OutputRates},
+        Proprietary,
+    },
+    Sentence,
+};
+
+use crate::{
+    args::{ReceiverArgs, ReceiverCommand, RestartMode, Switch},
+    serial,
+};
+
+/// Time for the receiver to switch to a new baud rate.
+const BAUD_RATE_DELAY: Duration = Duration::from_millis(250);
+
+pub fn run(args: &ReceiverArgs) -> Result<()> {
+    let commands = commands(&args.command)?;
+    let timeout = Duration::from_secs_f32(args.timeout);
+
+    let mut port = serial::open(&args.device, args.baud_rate, Duration::from_millis(100))?;
+    let mut reader = BufReader::new(port.try_clone()?);
+
+    for command in commands {
+        let sentence = command.encode();
+        println!("[*] Sending {}", sentence.trim_end());
+        port.write_all(sentence.as_bytes())
+            .context("Failed to send the command")?;
+
+        match wait_for_response(&mut reader, &command, timeout)? {
+            Some(response) => print_response(&response),
+            None if command.response_type().is_some() => {
+                println!("[-] No response within {:.1}s", timeout.as_secs_f32())
+            }
+            None => {}
+        }
+    }
+
+    if let ReceiverCommand::Baud { baud_rate } = args.command {
+        drop(reader);
+        drop(port);
+        thread::sleep(BAUD_RATE_DELAY);
+
+        let sample =
+            serial::sample_baud_rate(&args.device, baud_rate, serial::DEFAULT_SAMPLE_TIME)?;
+        match sample.valid {
+            0 => println!("[-] No valid sentences at {baud_rate} baud"),
+            _ => println!(
+                "[*] Receiving {:.1} sentences/s at {baud_rate} baud",
+                sample.score()
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+fn commands(command: &ReceiverCommand) -> Result<Vec<MtkCommand>> {
+    Ok(match command {
+        ReceiverCommand::Info => vec![
+            MtkCommand::QueryRelease,
+            MtkCommand::QueryFixInterval,
+            MtkCommand::QueryOutputRates,
+            MtkCommand::QuerySbasEnabled,
+        ],
+        ReceiverCommand::Rate { hz } => vec![MtkCommand::SetFixInterval(1000 / u16::from(*hz))],
+        ReceiverCommand::Baud { baud_rate } => vec![MtkCommand::SetBaudRate(*baud_rate)],
+        ReceiverCommand::Sentences { sentences } => {
+            if matches!(sentences.as_slice(), [x] if x.eq_ignore_ascii_case("default")) {
+                return Ok(vec![MtkCommand::ResetOutputRates]);
+            }
+
+            let mut rates = Vec::new();
+            for sentence in sentences {
+                let (name, rate) = match sentence.split_once('=') {
+                    Some((name, rate)) => (
+                        name,
+                        rate.parse()
+                            .with_context(|| format!("Invalid rate for {name}: {rate}"))?,
+                    ),
+                    None => (sentence.as_str(), 1),
+                };
+                rates.push((name, rate));
+            }
+
+            match OutputRates::from_rates(rates) {
+                Ok(rates) => vec![MtkCommand::SetOutputRates(rates)],
+                Err(name) => bail!("Unknown sentence: {name}"),
+            }
+        }
+        ReceiverCommand::Restart { mode } => vec![match mode {
+            RestartMode::Hot => MtkCommand::HotRestart,
+            RestartMode::Warm => MtkCommand::WarmRestart,
+            RestartMode::Cold => MtkCommand::ColdRestart,
+            RestartMode::Full => MtkCommand::FullColdRestart,
+        }],
+        ReceiverCommand::Sbas { state } => {
+            vec![MtkCommand::SetSbasEnabled(matches!(state, Switch::On))]
+        }
+    })
+}
+
+/// Reads sentences until one answers the command, or the timeout passes.
+fn wait_for_response(
+    reader: &mut impl BufRead,
+    command: &MtkCommand,
+    timeout: Duration,
+) -> Result<Option<MtkSentence>> {
+    if command.response_type().is_none() {
+        return Ok(None);
+    }
+
+    let start = Instant::now();
+    let mut line = Vec::new();
+    while start.elapsed() < timeout {
+        match reader.read_until(b'\n', &mut line) {
+            Ok(_) => {}
+            Err(err) if err.kind() == io::ErrorKind::TimedOut => continue,
+            Err(err) => return Err(err).context("Failed to read from port"),
+        }
+
+        let sentence = serial::trim_line(&line);
+        let sentence = (!sentence.is_empty()).then(|| nmea_0183::Message::parse(sentence));
+        line.clear();
+        if let Some(Ok(nmea_0183::Message {
+            message: Sentence::Proprietary(Proprietary::Mtk(sentence)),
+            ..
+        })) = sentence
+        {
+            if command.is_response(&sentence) {
+                return Ok(Some(sentence));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+fn print_response(response: &MtkSentence) {
+    match response {
+        MtkSentence::Acknowledge(ack) => println!("[*] PMTK{:03}: {:?}", ack.command, ack.flag),
+        MtkSentence::SystemMessage(message) => println!("[*] Restarted: {message:?}"),
+        MtkSentence::FixInterval(interval) => println!(
+            "[*] Fix interval: {interval}ms ({:.1} Hz)",
+            1000.0 / f32::from((*interval).max(1))
+        ),
+        MtkSentence::SbasEnabled(enabled) => {
+            println!("[*] SBAS: {}", if *enabled { "on" } else { "off" })
+        }
+        MtkSentence::OutputRates(rates) => {
+            let enabled = rates
+                .enabled()
+                .map(|(name, rate)| match rate {
+                    1 => name.to_owned(),
+                    _ => format!("{name}={rate}"),
+                })
+                .collect::<Vec<_>>();
+            println!("[*] Sentences: {}", enabled.join(", "));
+        }
+        MtkSentence::Release(release) => {
+            println!(
+                "[*] Firmware: {} (build {}), model {}",
+                release.release, release.build_id, release.model
+            );
+        }
+        MtkSentence::Text(text) => println!("[*] {text}"),
+        MtkSentence::Other {
+            packet_type,
+            fields,
+        } => {
+            println!("[*] PMTK{packet_type:03}: {}", fields.join(","))
+        }
+    }
+}
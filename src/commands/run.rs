@@ -1,7 +1,6 @@
 use std::{
-    io::{BufRead, BufReader},
-    str,
-    sync::Arc,
+    io::{ErrorKind, Write},
+    sync::{mpsc, Arc},
     thread,
     time::Duration,
 };
@@ -10,42 +9,70 @@ use anyhow::Result;
 use eframe::NativeOptions;
 use parking_lot::Mutex;
 
-use crate::{
-    app::App,
-    args::RunArgs,
+use nmea_0183::{
+    error::Nmea0183Error,
+    framing::{Frame, FrameReader},
     log::Log,
-    nmea_0183::{self, error::Nmea0183Error, stores::Store},
+    stores::Store,
+    ubx::{self, error::UbxError},
 };
 
+use crate::{app::App, args::RunArgs, serial, signal_k};
+
+/// Talker of the sentences we send.
+const TALKER: [u8; 2] = *b"GP";
+
+/// Seconds between sending the waypoints of the active route.
+const ROUTE_INTERVAL: u32 = 10;
+
 pub fn run(args: &RunArgs) -> Result<()> {
     let log = Log::new();
-    let store = Arc::new(Mutex::new(Store::new(log.clone())));
-    let app = App::new(args.clone(), store.clone(), log.clone());
+    let mut store = Store::new(log.clone());
+    store.depth.offset = args.depth_offset;
+    store.depth.shallow_threshold = args.shallow_alarm;
+    let store = Arc::new(Mutex::new(store));
 
-    let serial = serialport::new(args.device.as_str(), args.baud_rate)
-        .timeout(Duration::from_secs_f32(args.timeout))
-        .open()?;
-    let mut reader = BufReader::new(serial);
+    // Sentences to send to the device
+    let (output, sentences) = mpsc::channel::<String>();
 
-    thread::spawn(move || loop {
-        let mut line = Vec::new();
-        reader.read_until(b'\n', &mut line).unwrap();
-        let end = line.len() - 2;
-
-        let msg = nmea_0183::Message::parse(&line[..end]);
-        match msg {
-            Ok(msg) => store.lock().handle(msg.message),
-            Err(Nmea0183Error::UnknownType(..)) => {}
-            Err(err) => {
-                log.warning(format!("NMEA Error: {:?}", err));
-                eprintln!(
-                    "[-] NMEA Error: {:?}\n |  {}",
-                    err,
-                    str::from_utf8(&line[..end]).unwrap()
-                )
+    // A Signal K server replaces the serial device, there is nothing to send sentences to
+    if args.device.starts_with(signal_k::client::SCHEME) {
+        signal_k::client::spawn(&args.device, store.clone(), log.clone())?;
+        drop(sentences);
+    } else {
+        read_serial(args, store.clone(), log.clone(), sentences)?;
+    }
+
+    let app = App::new(args.clone(), store.clone(), log.clone(), output.clone());
+
+    if let Some(address) = &args.signal_k {
+        signal_k::server::spawn(address, store.clone(), log.clone())?;
+    }
+
+    if args.route_output {
+        let store = store.clone();
+        thread::spawn(move || {
+            for second in 0.. {
+                let sentences = {
+                    let store = store.lock();
+                    let mut sentences = store.routes.navigation_sentences(
+                        &store.location,
+                        store.heading.variation,
+                        TALKER,
+                    );
+                    if second % ROUTE_INTERVAL == 0 {
+                        sentences.extend(store.routes.route_sentences(TALKER));
+                    }
+                    sentences
+                };
+
+                for sentence in sentences.iter().filter_map(|x| x.encode()) {
+                    let _ = output.send(sentence);
+                }
+                thread::sleep(Duration::from_secs(1));
             }
-        }
-    });
+        });
+    }
 
     eframe::run_native(
         "Nautical Navigator",
@@ -56,3 +83,76 @@ pub fn run(args: &RunArgs) -> Result<()> {
 
     Ok(())
 }
+
+/// Opens the serial device, sending it the sentences and reading it into the store from background threads.
+fn read_serial(
+    args: &RunArgs,
+    store: Arc<Mutex<Store>>,
+    log: Log,
+    sentences: mpsc::Receiver<String>,
+) -> Result<()> {
+    let serial = serial::open(
+        &args.device,
+        args.baud_rate,
+        Duration::from_secs_f32(args.timeout),
+    )?;
+
+    let mut writer = serial.try_clone()?;
+    let writer_log = log.clone();
+    thread::spawn(move || {
+        for sentence in sentences {
+            if let Err(err) = writer.write_all(sentence.as_bytes()) {
+                writer_log.error(format!("Failed to send {}: {err}", sentence.trim_end()));
+                eprintln!("[-] Failed to send {}: {err}", sentence.trim_end());
+            }
+        }
+    });
+
+    let mut reader = FrameReader::new(serial);
+    let device = args.device.clone();
+
+    thread::spawn(move || loop {
+        let frame = match reader.next_frame() {
+            Ok(frame) => frame,
+            // Nothing was received within the timeout, the device may only be quiet
+            Err(err) if matches!(err.kind(), ErrorKind::TimedOut | ErrorKind::WouldBlock) => {
+                continue
+            }
+            Err(err) => {
+                log.error(format!("Failed to read from {device}: {err}"));
+                eprintln!("[-] Failed to read from {device}: {err}");
+                break;
+            }
+        };
+
+        match frame {
+            Frame::Nmea(line) => {
+                let message = nmea_0183::Message::parse(line);
+                let mut store = store.lock();
+                store.bus.handle(line, &message);
+                match message {
+                    Ok(msg) => store.handle(msg),
+                    Err(Nmea0183Error::UnknownType(..)) => {}
+                    Err(err) => {
+                        log.warning(format!("NMEA Error: {:?}", err));
+                        eprintln!(
+                            "[-] NMEA Error: {:?}\n |  {}",
+                            err,
+                            String::from_utf8_lossy(line)
+                        )
+                    }
+                }
+            }
+            Frame::Ubx(frame) => match ubx::Packet::parse(frame) {
+                Ok(packet) => store.lock().handle_ubx(packet),
+                Err(UbxError::UnknownMessage(..)) => {}
+                Err(err) => {
+                    log.warning(format!("UBX Error: {:?}", err));
+                    eprintln!("[-] UBX Error: {:?}", err);
+                }
+            },
+        }
+    });
+
+    Ok(())
+}
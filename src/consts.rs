@@ -1 +0,0 @@
-pub const HISTORY_SAMPLES: usize = 60;
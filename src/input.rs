@@ -0,0 +1,127 @@
+//! Sources of NMEA 0183 data, serial devices and recordings or network streams.
+
+use std::{
+    convert::Infallible,
+    fmt,
+    fs::File,
+    io::{self, Read},
+    net::{TcpStream, UdpSocket},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+
+use crate::{args::BaudRate, serial};
+
+/// Largest UDP datagram.
+const MAX_DATAGRAM_SIZE: usize = 65536;
+
+/// Where to read sentences and UBX frames from.
+#[derive(Debug, Clone)]
+pub enum Source {
+    /// A serial device.
+    Serial(String),
+    /// A log of the raw bytes received from a device.
+    File(PathBuf),
+    /// Standard input, `-`.
+    Stdin,
+    /// A TCP server, `tcp://host:port`, such as a multiplexer.
+    Tcp(String),
+    /// Datagrams sent to a local address, `udp://host:port`.
+    Udp(String),
+}
+
+impl FromStr for Source {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "-" {
+            return Ok(Self::Stdin);
+        }
+        if let Some(address) = s.strip_prefix("tcp://") {
+            return Ok(Self::Tcp(address.to_owned()));
+        }
+        if let Some(address) = s.strip_prefix("udp://") {
+            return Ok(Self::Udp(address.to_owned()));
+        }
+
+        // Serial devices are files too on unix, but not regular ones
+        match Path::new(s).is_file() {
+            true => Ok(Self::File(s.into())),
+            false => Ok(Self::Serial(s.to_owned())),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Serial(device) => f.write_str(device),
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => f.write_str("-"),
+            Source::Tcp(address) => write!(f, "tcp://{address}"),
+            Source::Udp(address) => write!(f, "udp://{address}"),
+        }
+    }
+}
+
+/// Opens a source, detecting the baud rate of serial devices if needed.
+/// Reads time out after `timeout` for serial devices and network streams.
+pub fn open(
+    source: &Source,
+    baud_rate: BaudRate,
+    timeout: Duration,
+) -> Result<Box<dyn Read + Send>> {
+    Ok(match source {
+        Source::Serial(device) => Box::new(serial::open(device, baud_rate, timeout)?),
+        Source::File(path) => Box::new(File::open(path).context("Failed to open log")?),
+        Source::Stdin => Box::new(io::stdin()),
+        Source::Tcp(address) => {
+            let stream = TcpStream::connect(address).context("Failed to connect")?;
+            stream.set_read_timeout(Some(timeout))?;
+            Box::new(stream)
+        }
+        Source::Udp(address) => {
+            let socket = UdpSocket::bind(address).context("Failed to bind socket")?;
+            socket.set_read_timeout(Some(timeout))?;
+            Box::new(UdpReader::new(socket))
+        }
+    })
+}
+
+/// Reads the datagrams received by a socket as a stream.
+/// Datagrams are buffered whole, so they aren't truncated by small reads.
+struct UdpReader {
+    socket: UdpSocket,
+    datagram: Box<[u8]>,
+    start: usize,
+    end: usize,
+}
+
+impl UdpReader {
+    fn new(socket: UdpSocket) -> Self {
+        Self {
+            socket,
+            datagram: vec![0; MAX_DATAGRAM_SIZE].into_boxed_slice(),
+            start: 0,
+            end: 0,
+        }
+    }
+}
+
+impl Read for UdpReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        // Empty datagrams would look like the end of the stream
+        while self.start == self.end {
+            self.end = self.socket.recv(&mut self.datagram)?;
+            self.start = 0;
+        }
+
+        let len = buf.len().min(self.end - self.start);
+        buf[..len].copy_from_slice(&self.datagram[self.start..self.start + len]);
+        self.start += len;
+        Ok(len)
+    }
+}
@@ -5,26 +5,24 @@ use clap::Parser;
 mod app;
 mod args;
 mod commands;
-mod consts;
-mod iso_8211;
-mod log;
+mod input;
 mod misc;
-mod nmea_0183;
+mod serial;
+mod signal_k;
+mod widgets;
 
 fn main() -> Result<()> {
-    let enc = ::std::fs::File::open(r"V:\Downloads\EncMaps\US\US556660\US556660.000").unwrap();
-    let mut parser = iso_8211::parser::Parser::new(enc);
-    let ddr = iso_8211::data_descriptive_record::DataDescriptiveRecord::parse(&mut parser)?;
-    dbg!(ddr);
-
-    return Ok(());
-
     let args = Args::parse();
 
     match args.subcommand {
         SubCommand::Run(args) => commands::run::run(&args)?,
         SubCommand::Devices(args) => commands::devices::run(&args)?,
         SubCommand::Debug(args) => commands::debug::run(&args)?,
+        SubCommand::Clock(args) => commands::clock::run(&args)?,
+        SubCommand::Receiver(args) => commands::receiver::run(&args)?,
+        SubCommand::Bench(args) => commands::bench::run(&args)?,
+        #[cfg(feature = "s57")]
+        SubCommand::Chart(args) => commands::chart::run(&args)?,
     }
 
     Ok(())
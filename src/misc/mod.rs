@@ -1,3 +1,3 @@
-pub mod average;
-pub mod delayed;
+#[cfg(unix)]
+pub mod ntp_shm;
 pub mod nullable;
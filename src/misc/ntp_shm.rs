@@ -0,0 +1,115 @@
+//! Writer for the shared memory reference clock used by ntpd (driver 28) and chrony (`refclock SHM`).
+//!
+//! ## References
+//! - [ntpd SHM driver](https://www.ntp.org/documentation/drivers/driver28/)
+
+use std::{io, ptr, sync::atomic};
+
+use chrono::{DateTime, Utc};
+
+/// Base key of the segments, `NTP0`.
+const KEY_BASE: i32 = 0x4E54_5030;
+
+/// Layout of the shared memory segment, from ntpd's `refclock_shm.c`.
+#[repr(C)]
+struct ShmTime {
+    mode: i32,
+    count: i32,
+    clock_time_stamp_sec: libc::time_t,
+    clock_time_stamp_usec: i32,
+    receive_time_stamp_sec: libc::time_t,
+    receive_time_stamp_usec: i32,
+    leap: i32,
+    precision: i32,
+    nsamples: i32,
+    valid: i32,
+    clock_time_stamp_nsec: u32,
+    receive_time_stamp_nsec: u32,
+    dummy: [i32; 8],
+}
+
+pub struct NtpShm {
+    segment: *mut ShmTime,
+}
+
+impl NtpShm {
+    /// Attaches to the segment of the given unit, creating it if needed.
+    /// Units 0 and 1 are only accessible by root.
+    pub fn open(unit: u8) -> io::Result<Self> {
+        let permissions = if unit <= 1 { 0o600 } else { 0o666 };
+        let key = KEY_BASE + i32::from(unit);
+
+        // SAFETY: The segment is only used through the pointer returned by `shmat`,
+        // which is checked for errors and is at least the size of `ShmTime`.
+        unsafe {
+            let id = libc::shmget(
+                key,
+                std::mem::size_of::<ShmTime>(),
+                libc::IPC_CREAT | permissions,
+            );
+            if id == -1 {
+                return Err(io::Error::last_os_error());
+            }
+
+            let segment = libc::shmat(id, ptr::null(), 0);
+            if segment as isize == -1 {
+                return Err(io::Error::last_os_error());
+            }
+
+            Ok(Self {
+                segment: segment as *mut ShmTime,
+            })
+        }
+    }
+
+    /// Writes a sample, with the time from the clock and the system time it was received at.
+    /// The precision is the log2 of the sample's precision in seconds.
+    pub fn write(&mut self, clock: DateTime<Utc>, received: DateTime<Utc>, precision: i32) {
+        // SAFETY: The segment is valid while self is alive. ntpd reads it and clears `valid`
+        // concurrently, so fields are only accessed through raw pointers with volatile reads and writes,
+        // and `count` and `valid` let it detect writes in progress.
+        unsafe {
+            let segment = self.segment;
+            macro_rules! set {
+                ($field:ident, $value:expr) => {
+                    ptr::write_volatile(ptr::addr_of_mut!((*segment).$field), $value)
+                };
+            }
+            let count = ptr::addr_of_mut!((*segment).count);
+
+            set!(valid, 0);
+            set!(mode, 1);
+            ptr::write_volatile(count, ptr::read_volatile(count).wrapping_add(1));
+            atomic::fence(atomic::Ordering::SeqCst);
+
+            set!(clock_time_stamp_sec, clock.timestamp() as libc::time_t);
+            set!(
+                clock_time_stamp_usec,
+                clock.timestamp_subsec_micros() as i32
+            );
+            set!(clock_time_stamp_nsec, clock.timestamp_subsec_nanos());
+            set!(receive_time_stamp_sec, received.timestamp() as libc::time_t);
+            set!(
+                receive_time_stamp_usec,
+                received.timestamp_subsec_micros() as i32
+            );
+            set!(receive_time_stamp_nsec, received.timestamp_subsec_nanos());
+            set!(leap, 0);
+            set!(precision, precision);
+            set!(nsamples, 3);
+
+            atomic::fence(atomic::Ordering::SeqCst);
+            ptr::write_volatile(count, ptr::read_volatile(count).wrapping_add(1));
+            set!(valid, 1);
+        }
+    }
+}
+
+impl Drop for NtpShm {
+    fn drop(&mut self) {
+        // SAFETY: The segment was attached in `open` and is not used after this.
+        unsafe {
+            libc::shmdt(self.segment as *const libc::c_void);
+        }
+    }
+}
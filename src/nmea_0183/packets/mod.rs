@@ -1,5 +0,0 @@
-pub mod active_satellites;
-pub mod geographic_position;
-pub mod ground_speed;
-pub mod satellites_in_view;
-pub mod text;
\ No newline at end of file
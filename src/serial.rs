@@ -0,0 +1,162 @@
+use std::{
+    collections::BTreeSet,
+    io::{self, Read},
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use serialport::{ClearBuffer, SerialPort};
+
+use nmea_0183::{
+    framing::MAX_LINE_LENGTH,
+    view::{Address, SentenceView},
+};
+
+use crate::args::BaudRate;
+
+/// Baud rates tried when detecting, most common for NMEA 0183 devices first.
+pub const COMMON_BAUD_RATES: [u32; 6] = [4800, 9600, 38400, 115200, 19200, 57600];
+/// Time spent listening at each baud rate when auto detecting.
+pub const DEFAULT_SAMPLE_TIME: Duration = Duration::from_millis(1500);
+
+/// The result of listening to a device at a single baud rate.
+#[derive(Debug)]
+pub struct Sample {
+    pub baud_rate: u32,
+    /// Number of complete lines received.
+    pub lines: u32,
+    /// Number of lines that passed the checksum.
+    pub valid: u32,
+    /// How long the device was listened to.
+    pub elapsed: Duration,
+    /// Talker IDs of the valid sentences.
+    pub talkers: BTreeSet<[u8; 2]>,
+    /// Types of the valid sentences.
+    pub sentence_types: BTreeSet<[u8; 3]>,
+    /// Manufacturer IDs of the valid proprietary sentences.
+    pub manufacturers: BTreeSet<[u8; 3]>,
+}
+
+impl Sample {
+    /// Valid sentences per second.
+    pub fn score(&self) -> f32 {
+        self.valid as f32 / self.elapsed.as_secs_f32()
+    }
+}
+
+/// Opens a serial device, detecting the baud rate first if needed.
+pub fn open(device: &str, baud_rate: BaudRate, timeout: Duration) -> Result<Box<dyn SerialPort>> {
+    let baud_rate = match baud_rate {
+        BaudRate::Fixed(baud_rate) => baud_rate,
+        // On stderr, so they don't mix with output meant for other tools such as JSON
+        BaudRate::Auto => {
+            eprintln!("[*] Detecting baud rate of {device}");
+            let best = detect_baud_rate(device, DEFAULT_SAMPLE_TIME)?
+                .context("No NMEA 0183 traffic detected at any common baud rate")?;
+            eprintln!(
+                "[*] Using baud rate {} ({:.1} sentences/s)",
+                best.baud_rate,
+                best.score()
+            );
+            best.baud_rate
+        }
+    };
+
+    serialport::new(device, baud_rate)
+        .timeout(timeout)
+        .open()
+        .context("Failed to open port")
+}
+
+/// Listens at each of the [`COMMON_BAUD_RATES`] and returns the one with the most valid sentences per second.
+/// Returns `None` if no valid sentences were received at any baud rate.
+pub fn detect_baud_rate(device: &str, sample_time: Duration) -> Result<Option<Sample>> {
+    let mut best: Option<Sample> = None;
+    for baud_rate in COMMON_BAUD_RATES {
+        let sample = sample_baud_rate(device, baud_rate, sample_time)?;
+        if sample.valid > 0 && best.as_ref().is_none_or(|x| sample.score() > x.score()) {
+            best = Some(sample);
+        }
+    }
+
+    Ok(best)
+}
+
+/// Listens to a device at the given baud rate, counting how many of the received lines are valid NMEA 0183 sentences.
+pub fn sample_baud_rate(device: &str, baud_rate: u32, sample_time: Duration) -> Result<Sample> {
+    let mut port = serialport::new(device, baud_rate)
+        .timeout(Duration::from_millis(100))
+        .open()
+        .context("Failed to open port")?;
+    let _ = port.clear(ClearBuffer::Input);
+
+    let mut sample = Sample {
+        baud_rate,
+        lines: 0,
+        valid: 0,
+        elapsed: Duration::ZERO,
+        talkers: BTreeSet::new(),
+        sentence_types: BTreeSet::new(),
+        manufacturers: BTreeSet::new(),
+    };
+
+    let mut line = Vec::new();
+    let mut buf = [0; 256];
+    let start = Instant::now();
+
+    while start.elapsed() < sample_time {
+        let read = match port.read(&mut buf) {
+            Ok(read) => read,
+            Err(err) if err.kind() == io::ErrorKind::TimedOut => continue,
+            Err(err) => return Err(err).context("Failed to read from port"),
+        };
+
+        for &byte in &buf[..read] {
+            if byte != b'\n' {
+                line.push(byte);
+                continue;
+            }
+
+            sample.lines += 1;
+            if let Some(view) = valid_sentence(trim_line(&line)) {
+                sample.valid += 1;
+                match view.address {
+                    Address::Standard {
+                        talker,
+                        sentence_type,
+                    } => {
+                        sample.talkers.insert(talker);
+                        sample.sentence_types.insert(sentence_type);
+                    }
+                    Address::Proprietary { manufacturer } => {
+                        sample.manufacturers.insert(manufacturer);
+                    }
+                }
+            }
+            line.clear();
+        }
+
+        if line.len() > MAX_LINE_LENGTH {
+            line.clear();
+        }
+    }
+
+    sample.elapsed = start.elapsed();
+    Ok(sample)
+}
+
+/// Removes the trailing line ending from a line.
+pub fn trim_line(line: &[u8]) -> &[u8] {
+    let mut end = line.len();
+    while end > 0 && matches!(line[end - 1], b'\r' | b'\n') {
+        end -= 1;
+    }
+
+    &line[..end]
+}
+
+/// Checks if a line is a framed sentence with a valid checksum.
+/// The fields aren't decoded, a sentence with an unknown type or invalid field was still received correctly.
+fn valid_sentence(line: &[u8]) -> Option<SentenceView<'_>> {
+    SentenceView::parse(line).ok()
+}
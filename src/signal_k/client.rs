@@ -0,0 +1,424 @@
+//! A Signal K client, reading the deltas of a server into the store in place of a serial device.
+//! Values are turned back into the sentences that carry them, so the stores handle them like any NMEA 0183 data.
+
+use std::{collections::HashMap, net::TcpStream, sync::Arc, thread, time::Duration};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Datelike, Timelike, Utc};
+use parking_lot::Mutex;
+use serde_json::Value;
+use tungstenite::{stream::MaybeTlsStream, Message as WsMessage, WebSocket};
+
+use nmea_0183::{
+    coordinate::Coordinate,
+    faa_mode::FaaMode,
+    log::Log,
+    packets::{
+        depth_below::DepthBelow,
+        distance_log::DistanceLog,
+        geographic_position::{GeographicPosition, Status},
+        ground_speed::GroundSpeed,
+        heading_deviation_variation::HeadingDeviationVariation,
+        magnetic_heading::MagneticHeading,
+        rate_of_turn::RateOfTurn,
+        time_date::TimeDate,
+        true_heading::TrueHeading,
+        water_speed_heading::WaterSpeedHeading,
+        water_temperature::WaterTemperature,
+        wind_direction_speed::WindDirectionSpeed,
+        wind_speed_angle::{SpeedUnit, WindReference, WindSpeedAngle},
+    },
+    stores::Store,
+    tag_block::TagBlock,
+    talker::Talker,
+    time::{Date, Time},
+    variation::Variation,
+    Message, Sentence,
+};
+
+use super::{SELF, STREAM_PATH};
+
+/// Scheme of Signal K stream addresses, `ws://host:port` or the full stream path.
+pub const SCHEME: &str = "ws://";
+/// Talker of the sentences made from Signal K values.
+const TALKER: [u8; 2] = *b"SK";
+/// Time to wait before reconnecting to a server.
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(5);
+
+const METERS_PER_NAUTICAL_MILE: f64 = 1852.0;
+const ZERO_CELSIUS: f64 = 273.15;
+
+type Socket = WebSocket<MaybeTlsStream<TcpStream>>;
+
+/// Connects to the server and reads its stream into the store from a background thread,
+/// reconnecting when the connection is lost.
+pub fn spawn(url: &str, store: Arc<Mutex<Store>>, log: Log) -> Result<()> {
+    let url = match url.strip_prefix(SCHEME) {
+        Some(address) if !address.contains('/') => format!("{url}{STREAM_PATH}"),
+        _ => url.to_owned(),
+    };
+    let socket = connect(&url)?;
+    log.info(format!("Connected to Signal K server {url}"));
+    println!("[*] Connected to Signal K server {url}");
+
+    thread::spawn(move || {
+        let mut socket = Some(socket);
+        loop {
+            let result = match socket.take() {
+                Some(socket) => read(socket, &store, &log),
+                None => connect(&url).and_then(|socket| read(socket, &store, &log)),
+            };
+            match result {
+                Ok(()) => {
+                    log.warning("Signal K server closed the connection");
+                    eprintln!("[-] Signal K server {url} closed the connection");
+                }
+                Err(err) => {
+                    log.warning(format!("Signal K error: {err}"));
+                    eprintln!("[-] Signal K error: {err}");
+                }
+            }
+            thread::sleep(RECONNECT_INTERVAL);
+        }
+    });
+
+    Ok(())
+}
+
+fn connect(url: &str) -> Result<Socket> {
+    let (socket, _) = tungstenite::connect(url).context("Failed to connect to Signal K server")?;
+    Ok(socket)
+}
+
+/// Reads messages until the connection is closed.
+fn read(mut socket: Socket, store: &Mutex<Store>, log: &Log) -> Result<()> {
+    // Named by the hello message, deltas about other vessels are ignored
+    let mut context = SELF.to_owned();
+    loop {
+        let text = match socket.read() {
+            Ok(WsMessage::Text(text)) => text,
+            Ok(_) => continue,
+            Err(tungstenite::Error::ConnectionClosed) => return Ok(()),
+            Err(err) => return Err(err.into()),
+        };
+
+        let message: Value = match serde_json::from_str(&text) {
+            Ok(message) => message,
+            Err(err) => {
+                log.warning(format!("Invalid Signal K message: {err}"));
+                eprintln!("[-] Invalid Signal K message: {err}\n |  {text}");
+                continue;
+            }
+        };
+
+        if let Some(id) = message["self"].as_str() {
+            context = id.to_owned();
+        }
+        if message["context"].as_str().is_some_and(|x| x != context) {
+            continue;
+        }
+
+        let mut store = store.lock();
+        for update in message["updates"].as_array().into_iter().flatten() {
+            for message in messages(update, &store) {
+                store.handle(message);
+            }
+        }
+    }
+}
+
+/// Turns the values of an update into messages.
+/// The source and time of the update are kept in a TAG block.
+fn messages(update: &Value, store: &Store) -> Vec<Message> {
+    let values: HashMap<&str, &Value> = update["values"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|x| Some((x["path"].as_str()?, &x["value"])))
+        .collect();
+
+    let tag_block = TagBlock {
+        time: update["timestamp"].as_str().and_then(parse_time),
+        source: update["source"]["label"]
+            .as_str()
+            .or(update["$source"].as_str())
+            .map(str::to_owned),
+        ..Default::default()
+    };
+
+    sentences(&values, store)
+        .into_iter()
+        .map(|message| Message {
+            talker: Talker::Id(TALKER),
+            tag_block: Some(tag_block.clone()),
+            message,
+        })
+        .collect()
+}
+
+fn parse_time(time: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(time)
+        .ok()
+        .map(|x| x.with_timezone(&Utc))
+}
+
+/// Makes the sentences carrying the values, in the units and ranges of NMEA 0183.
+/// Values sent together in a sentence are completed from the store when only some of them changed.
+fn sentences(values: &HashMap<&str, &Value>, store: &Store) -> Vec<Sentence> {
+    let number = |path| values.get(path).and_then(|x| x.as_f64());
+    let degrees = |path| number(path).map(|x| x.to_degrees() as f32);
+    let knots = |path| number(path).map(|x| (x * 3600.0 / METERS_PER_NAUTICAL_MILE) as f32);
+    let mut sentences = Vec::new();
+
+    let location = &store.location;
+    if let Some(position) = values.get("navigation.position") {
+        let coordinate = |name: &str| {
+            position[name]
+                .as_f64()
+                .map(|x| Coordinate { degree: x as f32 })
+        };
+        sentences.push(Sentence::Gll(GeographicPosition {
+            latitude: coordinate("latitude"),
+            longitude: coordinate("longitude"),
+            time: None,
+            status: Status::DataValid,
+            mode: FaaMode::Autonomous,
+        }));
+    }
+
+    let (course, speed) = (
+        degrees("navigation.courseOverGroundTrue"),
+        knots("navigation.speedOverGround"),
+    );
+    if course.is_some() || speed.is_some() {
+        sentences.push(Sentence::Vtg(GroundSpeed {
+            course_true: course.or(location.course),
+            course_magnetic: None,
+            speed_knots: speed.or(location.speed),
+            speed_kph: None,
+            faa_mode: FaaMode::Autonomous,
+        }));
+    }
+
+    let date_time = values
+        .get("navigation.datetime")
+        .and_then(|x| x.as_str())
+        .and_then(parse_time);
+    if let Some(date_time) = date_time {
+        sentences.push(Sentence::Zda(TimeDate {
+            time: Some(Time {
+                hour: date_time.hour() as u8,
+                min: date_time.minute() as u8,
+                sec: date_time.second() as f32 + date_time.nanosecond() as f32 / 1e9,
+            }),
+            date: Some(Date {
+                day: date_time.day() as u8,
+                month: date_time.month() as u8,
+                year: date_time.year() as u16,
+            }),
+            local_zone_hours: None,
+            local_zone_minutes: None,
+        }));
+    }
+
+    if let Some(heading) = degrees("navigation.headingTrue") {
+        sentences.push(Sentence::Hdt(TrueHeading {
+            heading: Some(heading.rem_euclid(360.0)),
+        }));
+    }
+    if let Some(heading) = degrees("navigation.headingMagnetic") {
+        sentences.push(Sentence::Hdm(MagneticHeading {
+            heading: Some(heading.rem_euclid(360.0)),
+        }));
+    }
+    if let Some(variation) = degrees("navigation.magneticVariation") {
+        sentences.push(Sentence::Hdg(HeadingDeviationVariation {
+            heading: None,
+            deviation: store.heading.deviation.map(|degree| Variation { degree }),
+            variation: Some(Variation { degree: variation }),
+        }));
+    }
+    if let Some(rate) = degrees("navigation.rateOfTurn") {
+        sentences.push(Sentence::Rot(RateOfTurn {
+            rate: Some(rate * 60.0),
+            status: Status::DataValid,
+        }));
+    }
+
+    let water = &store.water;
+    if let Some(speed) = knots("navigation.speedThroughWater") {
+        sentences.push(Sentence::Vhw(WaterSpeedHeading {
+            heading_true: None,
+            heading_magnetic: None,
+            speed_knots: Some(speed),
+            speed_kph: None,
+        }));
+    }
+    let nautical_miles = |path| number(path).map(|x| (x / METERS_PER_NAUTICAL_MILE) as f32);
+    let (total, trip) = (
+        nautical_miles("navigation.log"),
+        nautical_miles("navigation.trip.log"),
+    );
+    if total.is_some() || trip.is_some() {
+        sentences.push(Sentence::Vlw(DistanceLog {
+            total_water: total.or(water.total_distance),
+            trip_water: trip.or(water.trip_distance),
+            total_ground: water.total_ground_distance,
+            trip_ground: water.trip_ground_distance,
+        }));
+    }
+    if let Some(temperature) = number("environment.water.temperature") {
+        sentences.push(Sentence::Mtw(WaterTemperature {
+            temperature: Some((temperature - ZERO_CELSIUS) as f32),
+        }));
+    }
+
+    let depth = |path| number(path).map(|x| x as f32);
+    if let Some(depth) = depth("environment.depth.belowTransducer") {
+        sentences.push(Sentence::Dbt(DepthBelow {
+            feet: None,
+            meters: Some(depth),
+            fathoms: None,
+        }));
+    }
+    if let Some(depth) = depth("environment.depth.belowSurface") {
+        sentences.push(Sentence::Dbs(DepthBelow {
+            feet: None,
+            meters: Some(depth),
+            fathoms: None,
+        }));
+    }
+
+    // True wind goes first, so it isn't also calculated from the apparent wind.
+    // It is over ground, like the true wind the store calculates
+    let wind = &store.wind;
+    let true_speed = knots("environment.wind.speedOverGround");
+    let true_angle = degrees("environment.wind.angleTrueGround");
+    let true_direction = degrees("environment.wind.directionTrue");
+    if let Some(direction) = true_direction {
+        sentences.push(Sentence::Mwd(WindDirectionSpeed {
+            direction_true: Some(direction.rem_euclid(360.0)),
+            direction_magnetic: None,
+            speed_knots: true_speed.or(wind.true_speed.get().ok().copied()),
+            speed_mps: None,
+        }));
+    } else if true_angle.is_some() || true_speed.is_some() {
+        sentences.push(wind_speed_angle(
+            WindReference::True,
+            true_angle.or(wind.true_angle.get().ok().copied()),
+            true_speed.or(wind.true_speed.get().ok().copied()),
+        ));
+    }
+
+    let apparent_angle = degrees("environment.wind.angleApparent");
+    let apparent_speed = knots("environment.wind.speedApparent");
+    if apparent_angle.is_some() || apparent_speed.is_some() {
+        sentences.push(wind_speed_angle(
+            WindReference::Relative,
+            apparent_angle.or(wind.apparent_angle.get().ok().copied()),
+            apparent_speed.or(wind.apparent_speed.get().ok().copied()),
+        ));
+    }
+
+    sentences
+}
+
+fn wind_speed_angle(reference: WindReference, angle: Option<f32>, speed: Option<f32>) -> Sentence {
+    Sentence::Mwv(WindSpeedAngle {
+        angle: angle.map(|x| x.rem_euclid(360.0)),
+        reference,
+        speed,
+        unit: Some(SpeedUnit::Knots),
+        status: Status::DataValid,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::TcpListener, time::Instant};
+
+    use super::*;
+
+    const VESSEL: &str = "vessels.urn:mrn:imo:mmsi:230000001";
+
+    /// An update in Signal K units, radians, meters per second and kelvin.
+    const UPDATE: &str = r#"{
+        "context": "vessels.urn:mrn:imo:mmsi:230000001",
+        "updates": [{
+            "source": { "label": "n2k", "type": "NMEA2000" },
+            "timestamp": "2024-02-01T10:00:00.000Z",
+            "values": [
+                { "path": "navigation.position", "value": { "latitude": 60.15, "longitude": -24.95 } },
+                { "path": "navigation.courseOverGroundTrue", "value": 1.5707963 },
+                { "path": "navigation.speedOverGround", "value": 2.5722222 },
+                { "path": "navigation.headingTrue", "value": 3.1415927 },
+                { "path": "environment.depth.belowTransducer", "value": 4.2 },
+                { "path": "environment.wind.angleApparent", "value": -0.5235988 },
+                { "path": "environment.wind.speedApparent", "value": 5.1444444 },
+                { "path": "environment.wind.angleTrueGround", "value": 0.7853982 },
+                { "path": "environment.wind.speedOverGround", "value": 7.7166667 }
+            ]
+        }]
+    }"#;
+
+    /// An update about another vessel, which is ignored.
+    const OTHER_VESSEL: &str = r#"{
+        "context": "vessels.urn:mrn:imo:mmsi:230000002",
+        "updates": [{ "values": [{ "path": "navigation.log", "value": 1852 }] }]
+    }"#;
+
+    fn assert_close(value: Option<f32>, expected: f32) {
+        let value = value.expect("Value should be set");
+        assert!(
+            (value - expected).abs() < 1e-3,
+            "{value} should be {expected}"
+        );
+    }
+
+    #[test]
+    fn reads_values_in_nmea_units() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut socket = tungstenite::accept(stream).unwrap();
+            let hello = format!(r#"{{"name":"test","version":"1.7.0","self":"{VESSEL}"}}"#);
+            for message in [hello.as_str(), OTHER_VESSEL, UPDATE] {
+                socket.send(WsMessage::Text(message.to_owned())).unwrap();
+            }
+            // Keeps the connection open while the client reads
+            thread::sleep(Duration::from_secs(5));
+        });
+
+        let store = Arc::new(Mutex::new(Store::new(Log::new())));
+        spawn(&format!("ws://{address}"), store.clone(), Log::new()).unwrap();
+
+        // The values of an update are handled together
+        let start = Instant::now();
+        while store.lock().depth.below_transducer.get().ok().is_none() {
+            assert!(
+                start.elapsed() < Duration::from_secs(5),
+                "No update received"
+            );
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        let store = store.lock();
+        let location = &store.location;
+        assert_close(Some(location.latitude.degree), 60.15);
+        assert_close(Some(location.longitude.degree), -24.95);
+        assert_close(location.course, 90.0);
+        assert_close(location.speed, 5.0);
+        assert_close(store.heading.heading(), 180.0);
+        assert_close(store.depth.below_transducer.get().ok().copied(), 4.2);
+        // Relative angles are kept from -180 to 180 degrees, negative to port
+        assert_close(store.wind.apparent_angle.get().ok().copied(), -30.0);
+        assert_close(store.wind.apparent_speed.get().ok().copied(), 10.0);
+        assert_close(store.wind.true_angle.get().ok().copied(), 45.0);
+        assert_close(store.wind.true_speed.get().ok().copied(), 15.0);
+        assert_eq!(store.water.total_distance, None);
+
+        let source = store.sources.get("n2k").expect("Source should be recorded");
+        assert_eq!(Some(source.last_seen), parse_time("2024-02-01T10:00:00Z"));
+    }
+}
@@ -0,0 +1,241 @@
+//! Signal K, a JSON data model for boat data shared over WebSocket and HTTP.
+//! Values are in SI units: meters, meters per second, radians and kelvin.
+
+use chrono::{SecondsFormat, Utc};
+use serde_json::{json, Map, Value};
+
+use nmea_0183::{packets::geographic_position::Status, stores::Store};
+
+pub mod client;
+pub mod server;
+
+/// Context of our own vessel.
+pub const SELF: &str = "vessels.self";
+/// Version of the Signal K specification followed.
+pub const VERSION: &str = "1.7.0";
+/// Path of the WebSocket stream of deltas.
+pub const STREAM_PATH: &str = "/signalk/v1/stream";
+/// Label of the source of the values we send.
+const SOURCE: &str = "nautical-navigator";
+
+const METERS_PER_NAUTICAL_MILE: f64 = 1852.0;
+const ZERO_CELSIUS: f64 = 273.15;
+
+fn radians(degrees: f32) -> f64 {
+    f64::from(degrees).to_radians()
+}
+
+fn meters_per_second(knots: f32) -> f64 {
+    f64::from(knots) * METERS_PER_NAUTICAL_MILE / 3600.0
+}
+
+/// Gets the current values of the store as Signal K paths, skipping undefined and outdated values.
+pub fn values(store: &Store) -> Vec<(&'static str, Value)> {
+    let mut values = Vec::new();
+    let mut add = |path, value: Option<Value>| {
+        if let Some(value) = value {
+            values.push((path, value));
+        }
+    };
+
+    let location = &store.location;
+    if matches!(location.status, Status::DataValid) {
+        let mut position = json!({
+            "latitude": f64::from(location.latitude.degree),
+            "longitude": f64::from(location.longitude.degree),
+        });
+        if let Some(altitude) = location.altitude {
+            position["altitude"] = json!(f64::from(altitude));
+        }
+        add("navigation.position", Some(position));
+        add(
+            "navigation.courseOverGroundTrue",
+            location.course.map(|x| json!(radians(x))),
+        );
+        add(
+            "navigation.speedOverGround",
+            location.speed.map(|x| json!(meters_per_second(x))),
+        );
+    }
+    add(
+        "navigation.datetime",
+        location
+            .date_time
+            .map(|x| json!(x.to_rfc3339_opts(SecondsFormat::Millis, true))),
+    );
+    add(
+        "navigation.gnss.satellites",
+        location.satellites_used.map(|x| json!(x)),
+    );
+
+    let heading = &store.heading;
+    add(
+        "navigation.headingTrue",
+        heading.heading().map(|x| json!(radians(x))),
+    );
+    add(
+        "navigation.headingMagnetic",
+        heading
+            .magnetic_heading
+            .get()
+            .ok()
+            .map(|&x| json!(radians(x))),
+    );
+    add(
+        "navigation.magneticVariation",
+        heading.variation.map(|x| json!(radians(x))),
+    );
+    add(
+        "navigation.rateOfTurn",
+        heading
+            .rate_of_turn
+            .get()
+            .ok()
+            .map(|&x| json!(radians(x) / 60.0)),
+    );
+
+    let water = &store.water;
+    add(
+        "navigation.speedThroughWater",
+        water.speed.get().ok().map(|&x| json!(meters_per_second(x))),
+    );
+    add(
+        "navigation.log",
+        water
+            .total_distance
+            .map(|x| json!(f64::from(x) * METERS_PER_NAUTICAL_MILE)),
+    );
+    add(
+        "navigation.trip.log",
+        water
+            .trip_distance
+            .map(|x| json!(f64::from(x) * METERS_PER_NAUTICAL_MILE)),
+    );
+    add(
+        "environment.water.temperature",
+        water
+            .temperature
+            .get()
+            .ok()
+            .map(|&x| json!(f64::from(x) + ZERO_CELSIUS)),
+    );
+
+    // The transducer offset is to the waterline when positive, and to the keel when negative
+    let depth = &store.depth;
+    let below_transducer = depth.below_transducer.get().ok().copied();
+    let offset = depth.offset();
+    add(
+        "environment.depth.belowTransducer",
+        below_transducer.map(|x| json!(f64::from(x))),
+    );
+    add(
+        "environment.depth.belowSurface",
+        depth
+            .below_surface
+            .get()
+            .ok()
+            .copied()
+            .or(below_transducer
+                .filter(|_| offset > 0.0)
+                .map(|x| x + offset))
+            .map(|x| json!(f64::from(x))),
+    );
+    add(
+        "environment.depth.belowKeel",
+        below_transducer
+            .filter(|_| offset < 0.0)
+            .map(|x| json!(f64::from(x + offset))),
+    );
+
+    let wind = &store.wind;
+    add(
+        "environment.wind.angleApparent",
+        wind.apparent_angle.get().ok().map(|&x| json!(radians(x))),
+    );
+    add(
+        "environment.wind.speedApparent",
+        wind.apparent_speed
+            .get()
+            .ok()
+            .map(|&x| json!(meters_per_second(x))),
+    );
+    // The true wind is calculated from the course and speed over ground, not through the water
+    add(
+        "environment.wind.angleTrueGround",
+        wind.true_angle.get().ok().map(|&x| json!(radians(x))),
+    );
+    add(
+        "environment.wind.directionTrue",
+        wind.true_direction.get().ok().map(|&x| json!(radians(x))),
+    );
+    add(
+        "environment.wind.speedOverGround",
+        wind.true_speed
+            .get()
+            .ok()
+            .map(|&x| json!(meters_per_second(x))),
+    );
+
+    values
+}
+
+fn timestamp() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// Message sent when a client connects to the stream.
+pub fn hello() -> Value {
+    json!({
+        "name": SOURCE,
+        "version": VERSION,
+        "self": SELF,
+        "timestamp": timestamp(),
+        "roles": ["master", "main"],
+    })
+}
+
+/// Builds a delta message with the current values of the store.
+pub fn delta(store: &Store) -> Value {
+    let values: Vec<_> = values(store)
+        .into_iter()
+        .map(|(path, value)| json!({ "path": path, "value": value }))
+        .collect();
+
+    // The values can come from any input, so the source has no type
+    json!({
+        "context": SELF,
+        "updates": [{
+            "source": { "label": SOURCE },
+            "timestamp": timestamp(),
+            "values": values,
+        }],
+    })
+}
+
+/// Builds the full model, with the current values of the store under our own vessel.
+pub fn model(store: &Store) -> Value {
+    let timestamp = timestamp();
+    let mut vessel = Map::new();
+    for (path, value) in values(store) {
+        let (groups, key) = path.rsplit_once('.').unwrap();
+        let mut node = &mut vessel;
+        for group in groups.split('.') {
+            node = node
+                .entry(group)
+                .or_insert_with(|| Value::Object(Map::new()))
+                .as_object_mut()
+                .unwrap();
+        }
+
+        node.insert(
+            key.to_owned(),
+            json!({ "value": value, "timestamp": timestamp, "$source": SOURCE }),
+        );
+    }
+
+    json!({
+        "version": VERSION,
+        "self": SELF,
+        "vessels": { "self": vessel },
+    })
+}
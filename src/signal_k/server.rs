@@ -0,0 +1,223 @@
+//! A Signal K server, streaming deltas over WebSocket and serving the full model over HTTP.
+//! Every stream client receives all of our values, subscriptions are not supported.
+
+use std::{
+    io::{BufRead, BufReader, ErrorKind, Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context, Result};
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+use tungstenite::Message;
+
+use nmea_0183::{log::Log, stores::Store};
+
+use super::{STREAM_PATH, VERSION};
+
+/// Path of the discovery document.
+const DISCOVERY_PATH: &str = "/signalk";
+/// Path of the REST API serving the full model.
+const API_PATH: &str = "/signalk/v1/api";
+/// Time between deltas sent to stream clients.
+const UPDATE_INTERVAL: Duration = Duration::from_secs(1);
+/// Longest request line accepted.
+const MAX_REQUEST_LINE: usize = 4096;
+/// Longest time a client may take to send its request, so idle connections don't keep a thread.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Listens on the address and serves the store from a background thread.
+pub fn spawn(address: &str, store: Arc<Mutex<Store>>, log: Log) -> Result<()> {
+    let listener = TcpListener::bind(address).context("Failed to start the Signal K server")?;
+    let address = listener.local_addr()?;
+    log.info(format!("Signal K server listening on {address}"));
+    println!("[*] Signal K server listening on http://{address}{DISCOVERY_PATH}");
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else {
+                continue;
+            };
+
+            let store = store.clone();
+            let log = log.clone();
+            thread::spawn(move || {
+                let peer = stream.peer_addr();
+                if let Err(err) = handle(stream, &store) {
+                    log.warning(format!("Signal K client error: {err}"));
+                    eprintln!("[-] Signal K client {peer:?}: {err:?}");
+                }
+            });
+        }
+    });
+
+    Ok(())
+}
+
+fn handle(stream: TcpStream, store: &Mutex<Store>) -> Result<()> {
+    // Also bounds each read of the WebSocket handshake and the HTTP headers
+    stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+    let deadline = Instant::now() + REQUEST_TIMEOUT;
+
+    // The WebSocket handshake reads the request itself, so only peek at the path
+    let path = peek_path(&stream, deadline)?;
+    match path.split('?').next().unwrap_or_default() {
+        STREAM_PATH => stream_deltas(stream, store),
+        _ => serve_http(stream, store, deadline),
+    }
+}
+
+/// Gets the path of the request line without consuming it.
+fn peek_path(stream: &TcpStream, deadline: Instant) -> Result<String> {
+    let mut buffer = [0; MAX_REQUEST_LINE];
+    let line = loop {
+        let len = stream
+            .peek(&mut buffer)
+            .context("Failed to read the request")?;
+        if len == 0 {
+            bail!("Connection closed before the request");
+        }
+        if let Some(end) = buffer[..len].iter().position(|&x| x == b'\n') {
+            break &buffer[..end];
+        }
+        if len == buffer.len() {
+            bail!("Request line is too long");
+        }
+        if Instant::now() >= deadline {
+            bail!("Timed out waiting for the request");
+        }
+
+        // Peeking doesn't wait for more data than is already received
+        thread::sleep(Duration::from_millis(10));
+    };
+
+    let line = String::from_utf8_lossy(line);
+    match line.split_whitespace().nth(1) {
+        Some(path) => Ok(path.to_owned()),
+        None => bail!("Invalid request line: {}", line.trim_end()),
+    }
+}
+
+fn stream_deltas(stream: TcpStream, store: &Mutex<Store>) -> Result<()> {
+    let mut socket = tungstenite::accept(stream).context("WebSocket handshake failed")?;
+    socket.send(Message::Text(super::hello().to_string()))?;
+
+    // Reads time out so deltas keep being sent, while pings and closing are still answered
+    socket.get_ref().set_read_timeout(Some(UPDATE_INTERVAL))?;
+    let mut last_update = Instant::now();
+    socket.send(Message::Text(super::delta(&store.lock()).to_string()))?;
+    loop {
+        if last_update.elapsed() >= UPDATE_INTERVAL {
+            last_update = Instant::now();
+            let delta = super::delta(&store.lock());
+            socket.send(Message::Text(delta.to_string()))?;
+        }
+
+        match socket.read() {
+            Ok(_) => {}
+            Err(tungstenite::Error::Io(err))
+                if matches!(err.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+            Err(tungstenite::Error::ConnectionClosed) => return Ok(()),
+            Err(err) => return Err(err.into()),
+        }
+    }
+}
+
+fn serve_http(stream: TcpStream, store: &Mutex<Store>, deadline: Instant) -> Result<()> {
+    let mut reader = BufReader::new(stream);
+    let request = read_line(&mut reader, deadline)?;
+
+    let mut host = None;
+    loop {
+        let line = read_line(&mut reader, deadline)?;
+        if line.trim_end().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("host") {
+                host = Some(value.trim().to_owned());
+            }
+        }
+    }
+
+    let mut stream = reader.into_inner();
+    let host = match host {
+        Some(host) => host,
+        None => stream.local_addr()?.to_string(),
+    };
+
+    let mut parts = request.split_whitespace();
+    let (method, path) = (parts.next(), parts.next().unwrap_or_default());
+    let path = path
+        .split('?')
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches('/');
+    if method != Some("GET") {
+        return respond(&mut stream, "405 Method Not Allowed", &json!({}));
+    }
+
+    if path == DISCOVERY_PATH {
+        let discovery = json!({
+            "endpoints": {
+                "v1": {
+                    "version": VERSION,
+                    "signalk-http": format!("http://{host}{API_PATH}/"),
+                    "signalk-ws": format!("ws://{host}{STREAM_PATH}"),
+                },
+            },
+            "server": { "id": "nautical-navigator", "version": env!("CARGO_PKG_VERSION") },
+        });
+        return respond(&mut stream, "200 OK", &discovery);
+    }
+
+    // Paths below the API select a part of the model, such as `vessels/self/navigation/position`
+    let model = super::model(&store.lock());
+    let part = path
+        .strip_prefix(API_PATH)
+        .and_then(|x| model.pointer(x).cloned());
+    match part {
+        Some(part) => respond(&mut stream, "200 OK", &part),
+        None => respond(&mut stream, "404 Not Found", &json!({})),
+    }
+}
+
+/// Reads a line of the request, empty at the end of the stream.
+/// Fails if the line is too long or the request isn't complete by the deadline.
+fn read_line(reader: &mut BufReader<TcpStream>, deadline: Instant) -> Result<String> {
+    let Some(remaining) = deadline.checked_duration_since(Instant::now()) else {
+        bail!("Timed out waiting for the request");
+    };
+    reader
+        .get_ref()
+        .set_read_timeout(Some(remaining.max(Duration::from_millis(1))))?;
+
+    let mut line = String::new();
+    reader
+        .by_ref()
+        .take(MAX_REQUEST_LINE as u64)
+        .read_line(&mut line)
+        .context("Failed to read the request")?;
+    if line.len() == MAX_REQUEST_LINE && !line.ends_with('\n') {
+        bail!("Request line is too long");
+    }
+    Ok(line)
+}
+
+fn respond(stream: &mut TcpStream, status: &str, body: &Value) -> Result<()> {
+    let body = body.to_string();
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\n\
+         Content-Type: application/json\r\n\
+         Content-Length: {}\r\n\
+         Access-Control-Allow-Origin: *\r\n\
+         Connection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()?;
+    Ok(())
+}
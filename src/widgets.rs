@@ -0,0 +1,210 @@
+use egui::{emath::Rot2, vec2, Align2, Color32, FontId, Response, Sense, Shape, Stroke, Ui, Vec2};
+
+pub const APPARENT_WIND_COLOR: Color32 = Color32::LIGHT_BLUE;
+pub const TRUE_WIND_COLOR: Color32 = Color32::GOLD;
+pub const ERROR_ELLIPSE_COLOR: Color32 = Color32::LIGHT_RED;
+pub const NEEDLE_COLOR: Color32 = Color32::LIGHT_GREEN;
+
+/// Draws the own ship symbol, pointing in the given direction (degrees from true north).
+/// A dot is drawn if the direction is unknown.
+pub fn own_ship(ui: &mut Ui, direction: Option<f32>, size: f32) -> Response {
+    let (response, painter) = ui.allocate_painter(Vec2::splat(size), Sense::hover());
+    let center = response.rect.center();
+    let radius = size / 2.0;
+    let color = ui.visuals().text_color();
+
+    painter.circle_stroke(center, radius - 1.0, Stroke::new(1.0, color));
+    painter.text(
+        center - vec2(0.0, radius - 2.0),
+        Align2::CENTER_TOP,
+        "N",
+        FontId::proportional(radius * 0.3),
+        color,
+    );
+
+    let Some(direction) = direction else {
+        painter.circle_filled(center, radius * 0.1, color);
+        return response;
+    };
+
+    let rotation = Rot2::from_angle(direction.to_radians());
+    let points = [vec2(0.0, -0.6), vec2(0.3, 0.5), vec2(-0.3, 0.5)]
+        .map(|point| center + rotation * (point * radius))
+        .to_vec();
+    painter.add(Shape::convex_polygon(points, color, Stroke::NONE));
+
+    response
+}
+
+/// Draws a wind rose with the bow pointing up.
+/// The angles are the direction the wind is coming from in degrees from the bow.
+pub fn wind_rose(
+    ui: &mut Ui,
+    apparent_angle: Option<f32>,
+    true_angle: Option<f32>,
+    size: f32,
+) -> Response {
+    let (response, painter) = ui.allocate_painter(Vec2::splat(size), Sense::hover());
+    let center = response.rect.center();
+    let radius = size / 2.0 - 1.0;
+    let color = ui.visuals().text_color();
+    let stroke = Stroke::new(1.0, color);
+
+    painter.circle_stroke(center, radius, stroke);
+    for i in 0..12 {
+        let direction = Rot2::from_angle((i as f32 * 30.0).to_radians()) * vec2(0.0, -1.0);
+        let length = if i % 3 == 0 { 0.15 } else { 0.07 };
+        painter.line_segment(
+            [
+                center + direction * radius,
+                center + direction * radius * (1.0 - length),
+            ],
+            stroke,
+        );
+    }
+
+    // Hull, bow up
+    let hull = [
+        vec2(0.0, -0.35),
+        vec2(0.12, -0.1),
+        vec2(0.1, 0.3),
+        vec2(-0.1, 0.3),
+        vec2(-0.12, -0.1),
+    ]
+    .map(|point| center + point * radius)
+    .to_vec();
+    painter.add(Shape::closed_line(hull, stroke));
+
+    for (angle, color) in [
+        (true_angle, TRUE_WIND_COLOR),
+        (apparent_angle, APPARENT_WIND_COLOR),
+    ] {
+        let Some(angle) = angle else {
+            continue;
+        };
+
+        // Arrow from the rim pointing in the direction the wind is blowing
+        let direction = Rot2::from_angle(angle.to_radians()) * vec2(0.0, -1.0);
+        let start = center + direction * radius * 0.95;
+        painter.arrow(start, -direction * radius * 0.5, Stroke::new(3.0, color));
+    }
+
+    response
+}
+
+/// Draws a half circle gauge for a value within a range.
+pub fn gauge(
+    ui: &mut Ui,
+    value: Option<f32>,
+    range: (f32, f32),
+    label: &str,
+    size: f32,
+) -> Response {
+    let (response, painter) = ui.allocate_painter(vec2(size, size * 0.6), Sense::hover());
+    let center = response.rect.center_bottom() - vec2(0.0, size * 0.1);
+    let radius = size / 2.0 - 2.0;
+    let color = ui.visuals().text_color();
+
+    // Arc from the left (min) to the right (max)
+    let point = |fraction: f32| {
+        let angle = std::f32::consts::PI * (1.0 - fraction);
+        center + vec2(angle.cos(), -angle.sin()) * radius
+    };
+    let arc = (0..=32).map(|i| point(i as f32 / 32.0)).collect::<Vec<_>>();
+    painter.add(Shape::line(arc, Stroke::new(2.0, color)));
+
+    let font = FontId::proportional(size * 0.08);
+    painter.text(
+        point(0.0),
+        Align2::LEFT_TOP,
+        format!("{}", range.0),
+        font.clone(),
+        color,
+    );
+    painter.text(
+        point(1.0),
+        Align2::RIGHT_TOP,
+        format!("{}", range.1),
+        font,
+        color,
+    );
+    painter.text(
+        center - vec2(0.0, radius * 0.35),
+        Align2::CENTER_BOTTOM,
+        label,
+        FontId::proportional(size * 0.1),
+        color,
+    );
+
+    if let Some(value) = value {
+        let fraction = ((value - range.0) / (range.1 - range.0)).clamp(0.0, 1.0);
+        let needle = point(fraction) - center;
+        painter.line_segment(
+            [center, center + needle * 0.9],
+            Stroke::new(3.0, NEEDLE_COLOR),
+        );
+    }
+    painter.circle_filled(center, 3.0, color);
+
+    response
+}
+
+/// Draws a position error ellipse, north up, scaled to fit.
+/// The axes are in meters and the orientation of the semi-major axis is in degrees from true north.
+pub fn error_ellipse(
+    ui: &mut Ui,
+    semi_major: f32,
+    semi_minor: f32,
+    orientation: f32,
+    size: f32,
+) -> Response {
+    let (response, painter) = ui.allocate_painter(Vec2::splat(size), Sense::hover());
+    let center = response.rect.center();
+    let radius = size / 2.0 - 1.0;
+    let color = ui.visuals().text_color();
+    let stroke = Stroke::new(1.0, ui.visuals().weak_text_color());
+
+    // Crosshair and a ring showing the scale
+    painter.line_segment(
+        [center - vec2(radius, 0.0), center + vec2(radius, 0.0)],
+        stroke,
+    );
+    painter.line_segment(
+        [center - vec2(0.0, radius), center + vec2(0.0, radius)],
+        stroke,
+    );
+    painter.circle_stroke(center, radius, stroke);
+    let font = FontId::proportional(size * 0.07);
+    painter.text(
+        center - vec2(0.0, radius - 2.0),
+        Align2::LEFT_TOP,
+        " N",
+        font.clone(),
+        color,
+    );
+
+    let scale = semi_major.max(semi_minor).max(f32::EPSILON) / 0.8;
+    painter.text(
+        center + vec2(radius, radius),
+        Align2::RIGHT_BOTTOM,
+        format!("{scale:.1}m"),
+        font,
+        color,
+    );
+
+    let rotation = Rot2::from_angle(orientation.to_radians());
+    let points = (0..64)
+        .map(|i| {
+            let t = i as f32 / 64.0 * std::f32::consts::TAU;
+            let point = vec2(semi_minor * t.cos(), -semi_major * t.sin()) / scale;
+            center + rotation * (point * radius)
+        })
+        .collect::<Vec<_>>();
+    painter.add(Shape::closed_line(
+        points,
+        Stroke::new(2.0, ERROR_ELLIPSE_COLOR),
+    ));
+    painter.circle_filled(center, 2.0, color);
+
+    response
+}